@@ -0,0 +1,71 @@
+//! Benchmarks parsing and both parts of every day against its real input, each measured on its
+//! own.
+//!
+//! Criterion keeps its results under `target/criterion` and compares every run with the last
+//! one. To compare across commits, save a named baseline and check later runs against it:
+//!
+//! ```text
+//! cargo bench -p benches -- --save-baseline main
+//! cargo bench -p benches -- --baseline main
+//! ```
+//!
+//! Inputs are found the same way as `aoc run` finds them, so `AOC_INPUTS` points the benchmarks
+//! at a directory of inputs. Days without an input are skipped. A filter such as `day-07` or
+//! `day-07/part_two` after `--` runs just those benchmarks.
+
+use std::hint::black_box;
+
+use common::{InputOptions, Puzzle, Solution};
+use criterion::{Criterion, criterion_group, criterion_main};
+
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8) {
+    let puzzle = Puzzle::workspace_day(env!("CARGO_MANIFEST_DIR"), day);
+    let name = &puzzle.name;
+
+    let input = match InputOptions::default().load(&puzzle) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("skipping {name}: {error}");
+            return;
+        }
+    };
+
+    let solution = S::default();
+    let parsed = match solution.parse(&input) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("skipping {name}:\n{}", error.render(&input));
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("parse", |b| b.iter(|| solution.parse(black_box(&input))));
+    group.bench_function("part_one", |b| {
+        b.iter(|| solution.part_one(black_box(&parsed)))
+    });
+    group.bench_function("part_two", |b| {
+        b.iter(|| solution.part_two(black_box(&parsed)))
+    });
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day_01::Day01>(c, 1);
+    bench_day::<day_02::Day02>(c, 2);
+    bench_day::<day_03::Day03>(c, 3);
+    bench_day::<day_04::Day04>(c, 4);
+    bench_day::<day_05::Day05>(c, 5);
+    bench_day::<day_06::Day06>(c, 6);
+    bench_day::<day_07::Day07>(c, 7);
+    bench_day::<day_08::Day08>(c, 8);
+    bench_day::<day_09::Day09>(c, 9);
+    bench_day::<day_10::Day10>(c, 10);
+    bench_day::<day_11::Day11>(c, 11);
+    bench_day::<day_12::Day12>(c, 12);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
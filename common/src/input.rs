@@ -0,0 +1,223 @@
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    mem,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable naming a directory of inputs, used when `--inputs-dir` isn't given.
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS";
+
+/// Where a day looks for its input when the command line doesn't say otherwise.
+#[derive(Debug, Clone)]
+pub struct Puzzle {
+    /// The day's crate name, e.g. `day-07`, which is also its file name in an inputs directory.
+    pub name: String,
+    /// The day's own `input.txt`.
+    pub path: PathBuf,
+    /// The input compiled into the binary with the `embedded-input` feature.
+    pub embedded: Option<&'static str>,
+    /// Whether the binary can embed the input at all. Only a day's own crate can.
+    pub embeddable: bool,
+}
+
+impl Puzzle {
+    /// Day `day` of the workspace, seen from another of its crates such as the runner.
+    /// `manifest_dir` is that crate's `CARGO_MANIFEST_DIR`, next to which the day crates live.
+    /// The input is read from the day's `input.txt` and is never embedded.
+    pub fn workspace_day(manifest_dir: &str, day: u8) -> Self {
+        let name = format!("day-{day:02}");
+
+        Self {
+            path: Path::new(manifest_dir)
+                .join("..")
+                .join(&name)
+                .join("input.txt"),
+            name,
+            embedded: None,
+            embeddable: false,
+        }
+    }
+}
+
+/// Describes the calling day crate, embedding its `input.txt` when the crate is built with the
+/// `embedded-input` feature.
+#[macro_export]
+macro_rules! puzzle {
+    () => {{
+        #[cfg(feature = "embedded-input")]
+        let embedded = Some(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/input.txt"
+        )));
+        #[cfg(not(feature = "embedded-input"))]
+        let embedded = None;
+
+        $crate::Puzzle {
+            name: String::from(env!("CARGO_PKG_NAME")),
+            path: ::std::path::PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")),
+            embedded,
+            embeddable: true,
+        }
+    }};
+}
+
+/// The input selection flags shared by every binary in the workspace.
+#[derive(Debug, Default, Clone)]
+pub struct InputOptions {
+    /// `--input <path>`, where a path of `-` reads stdin.
+    pub path: Option<String>,
+    /// `--inputs-dir <dir>`, a directory holding a `<day>.txt` for each day.
+    pub dir: Option<PathBuf>,
+}
+
+impl InputOptions {
+    /// Takes `--input` and `--inputs-dir` out of `args`, leaving the rest for the caller.
+    pub fn extract(args: &mut Vec<String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut remaining = mem::take(args).into_iter();
+
+        while let Some(arg) = remaining.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    options.path = Some(remaining.next().ok_or("`--input` needs a path")?);
+                }
+                "--inputs-dir" => {
+                    let dir = remaining.next().ok_or("`--inputs-dir` needs a directory")?;
+                    options.dir = Some(PathBuf::from(dir));
+                }
+                _ => args.push(arg),
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Reads the puzzle's input from, in order of preference: `--input`, the inputs directory
+    /// (`--inputs-dir` or `AOC_INPUTS`), the embedded input and finally the day's `input.txt`.
+    pub fn load(&self, puzzle: &Puzzle) -> Result<String, InputError> {
+        let source = match (&self.path, &self.dir) {
+            (Some(path), _) if path == "-" => InputSource::Stdin,
+            (Some(path), _) => InputSource::File(PathBuf::from(path)),
+            (None, Some(dir)) => InputSource::File(dir.join(format!("{}.txt", puzzle.name))),
+            (None, None) => match (env::var_os(INPUTS_DIR_VAR), puzzle.embedded) {
+                (Some(dir), _) => {
+                    InputSource::File(PathBuf::from(dir).join(format!("{}.txt", puzzle.name)))
+                }
+                (None, Some(embedded)) => return Ok(String::from(embedded)),
+                (None, None) => InputSource::File(puzzle.path.clone()),
+            },
+        };
+
+        let result = match &source {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+        };
+
+        result.map_err(|error| InputError {
+            source,
+            error,
+            embeddable: puzzle.embeddable,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "`{}`", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: InputSource,
+    pub error: io::Error,
+    /// Whether building with `embedded-input` is worth suggesting.
+    pub embeddable: bool,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "couldn't read input from {}: {}",
+            self.source, self.error
+        )?;
+
+        if self.error.kind() == io::ErrorKind::NotFound {
+            write!(f, "\nhint: pass `--input <path>` (`-` for stdin), ")?;
+            if self.embeddable {
+                write!(
+                    f,
+                    "point `--inputs-dir` or `{INPUTS_DIR_VAR}` at a directory of inputs, or \
+                     build with `--features embedded-input`"
+                )?;
+            } else {
+                write!(
+                    f,
+                    "or point `--inputs-dir` or `{INPUTS_DIR_VAR}` at a directory of inputs"
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_input_options() {
+        let mut args: Vec<String> = ["--part", "2", "--input", "-", "--inputs-dir", "inputs"]
+            .map(String::from)
+            .to_vec();
+
+        let options = InputOptions::extract(&mut args).unwrap();
+
+        assert_eq!(options.path.as_deref(), Some("-"));
+        assert_eq!(options.dir, Some(PathBuf::from("inputs")));
+        assert_eq!(args, ["--part", "2"]);
+    }
+
+    #[test]
+    fn hint_only_offers_embedding_to_days() {
+        let options = InputOptions {
+            path: None,
+            dir: Some(PathBuf::from("/nonexistent")),
+        };
+
+        let error = options
+            .load(&Puzzle::workspace_day(env!("CARGO_MANIFEST_DIR"), 7))
+            .unwrap_err();
+        assert!(!error.to_string().contains("embedded-input"));
+
+        let day = Puzzle {
+            embeddable: true,
+            ..Puzzle::workspace_day(env!("CARGO_MANIFEST_DIR"), 7)
+        };
+        let error = options.load(&day).unwrap_err();
+        assert!(error.to_string().contains("embedded-input"));
+    }
+}
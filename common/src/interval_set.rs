@@ -0,0 +1,255 @@
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+/// An integer type that can bound the intervals of an [`IntervalSet`].
+pub trait Endpoint: Copy + Ord {
+    const ZERO: Self;
+
+    /// The next value up, if there is one.
+    fn successor(self) -> Option<Self>;
+
+    /// The next value down, if there is one.
+    fn predecessor(self) -> Option<Self>;
+
+    /// How many values `start..=end` covers, or `None` if that doesn't fit in the type, as
+    /// with `0..=u64::MAX`.
+    fn count(start: Self, end: Self) -> Option<Self>;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_endpoint {
+    ($($int:ty),*) => {$(
+        impl Endpoint for $int {
+            const ZERO: Self = 0;
+
+            fn successor(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn predecessor(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn count(start: Self, end: Self) -> Option<Self> {
+                end.checked_sub(start)?.checked_add(1)
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$int>::checked_add(self, other)
+            }
+        }
+    )*};
+}
+
+impl_endpoint!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// A set of integers stored as sorted, disjoint, non-adjacent inclusive intervals. Inserting
+/// merges overlapping and touching intervals, so `1..=3` and `4..=6` become `1..=6`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    /// Each interval's end, keyed by its start.
+    intervals: BTreeMap<T, T>,
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: BTreeMap::new(),
+        }
+    }
+
+    /// Adds every value in `range`, merging it with any intervals it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        if let Some((&before_start, &before_end)) = self.intervals.range(..=start).next_back()
+            && touches(before_end, start)
+        {
+            start = before_start;
+            end = end.max(before_end);
+        }
+
+        while let Some((&after_start, &after_end)) = self.intervals.range(start..).next()
+            && touches(end, after_start)
+        {
+            self.intervals.remove(&after_start);
+            end = end.max(after_end);
+        }
+
+        self.intervals.insert(start, end);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.interval(value).is_some()
+    }
+
+    /// The interval holding `value`, if any.
+    pub fn interval(&self, value: T) -> Option<RangeInclusive<T>> {
+        self.intervals
+            .range(..=value)
+            .next_back()
+            .filter(|&(_, &end)| value <= end)
+            .map(|(&start, &end)| start..=end)
+    }
+
+    /// How many values the set holds, summed over its intervals, or `None` if that doesn't fit
+    /// in `T`.
+    pub fn len(&self) -> Option<T> {
+        self.intervals
+            .iter()
+            .try_fold(T::ZERO, |len, (&start, &end)| {
+                len.checked_add(T::count(start, end)?)
+            })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The intervals in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|(&start, &end)| start..=end)
+    }
+
+    /// The values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut result = Self::new();
+        let mut ours = self.iter().peekable();
+        let mut theirs = other.iter().peekable();
+
+        while let (Some(a), Some(b)) = (ours.peek(), theirs.peek()) {
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+
+            if start <= end {
+                result.intervals.insert(start, end);
+            }
+
+            if a.end() < b.end() {
+                ours.next();
+            } else {
+                theirs.next();
+            }
+        }
+
+        result
+    }
+
+    /// The values in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = Self::new();
+
+        for range in self.iter() {
+            let (mut start, end) = range.into_inner();
+            let overlapping = other
+                .intervals
+                .range(..=end)
+                .rev()
+                .take_while(|&(_, &other_end)| other_end >= start)
+                .map(|(&other_start, &other_end)| (other_start, other_end))
+                .collect::<Vec<_>>();
+
+            for (other_start, other_end) in overlapping.into_iter().rev() {
+                if let Some(before) = other_start.predecessor()
+                    && start <= before
+                {
+                    result.intervals.insert(start, before);
+                }
+
+                match other_end.successor() {
+                    Some(after) => start = after,
+                    None => break,
+                }
+            }
+
+            if start <= end && !other.contains(end) {
+                result.intervals.insert(start, end);
+            }
+        }
+
+        result
+    }
+}
+
+/// Whether an interval ending at `end` overlaps or touches one starting at `start`.
+fn touches<T: Endpoint>(end: T, start: T) -> bool {
+    end.successor().is_none_or(|next| next >= start)
+}
+
+impl<T: Endpoint> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Endpoint> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_merges_overlapping_and_touching() {
+        let set: IntervalSet<u64> = [10..=14, 3..=5, 16..=20, 12..=18, 6..=7]
+            .into_iter()
+            .collect();
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3..=7, 10..=20]);
+        assert_eq!(set.len(), Some(16));
+        assert!(set.contains(7));
+        assert!(!set.contains(8));
+        assert_eq!(set.interval(15), Some(10..=20));
+    }
+
+    #[test]
+    fn intersection_and_difference() {
+        let a: IntervalSet<i32> = [0..=10, 20..=30].into_iter().collect();
+        let b: IntervalSet<i32> = [-5..=2, 5..=6, 9..=22, 30..=40].into_iter().collect();
+
+        assert_eq!(
+            a.intersection(&b).iter().collect::<Vec<_>>(),
+            [0..=2, 5..=6, 9..=10, 20..=22, 30..=30]
+        );
+        assert_eq!(
+            a.difference(&b).iter().collect::<Vec<_>>(),
+            [3..=4, 7..=8, 23..=29]
+        );
+    }
+
+    #[test]
+    fn endpoints_at_the_limits() {
+        let set: IntervalSet<u8> = [0..=10, 250..=255].into_iter().collect();
+        let holes: IntervalSet<u8> = [0..=0, 255..=255].into_iter().collect();
+
+        assert_eq!(
+            set.difference(&holes).iter().collect::<Vec<_>>(),
+            [1..=10, 250..=254]
+        );
+    }
+
+    #[test]
+    fn len_past_the_limits() {
+        let full: IntervalSet<u64> = [0..=u64::MAX].into_iter().collect();
+        assert_eq!(full.len(), None);
+
+        let halves: IntervalSet<u8> = [0..=127, 129..=255].into_iter().collect();
+        assert_eq!(halves.len(), Some(255));
+
+        let signed: IntervalSet<i8> = [-128..=-1, 1..=127].into_iter().collect();
+        assert_eq!(signed.len(), None);
+        assert_eq!(IntervalSet::<i8>::new().len(), Some(0));
+    }
+}
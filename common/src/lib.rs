@@ -0,0 +1,113 @@
+use std::{env, fmt::Display, process, time::Instant};
+
+mod disjoint_set;
+mod input;
+mod interval_set;
+mod parse;
+#[cfg(any(test, feature = "test-util"))]
+mod xorshift;
+
+pub use disjoint_set::DisjointSet;
+pub use input::{INPUTS_DIR_VAR, InputError, InputOptions, InputSource, Puzzle};
+pub use interval_set::{Endpoint, IntervalSet};
+pub use parse::{ParseError, Source, Span};
+#[cfg(any(test, feature = "test-util"))]
+pub use xorshift::XorShift;
+
+/// A day's puzzle, split into parsing the input and solving each part from the parsed input so
+/// the parts can be timed and reused independently.
+pub trait Solution: Default {
+    /// The parsed puzzle input, which may borrow from the raw input.
+    type Input<'src>;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse<'src>(&self, input: &'src str) -> Result<Self::Input<'src>, ParseError>;
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Self::PartOne;
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Self::PartTwo;
+}
+
+/// The answer to a part with no puzzle, like the second half of the last day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoAnswer;
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "-")
+    }
+}
+
+/// Parses the input and solves both parts, printing each answer with how long it took.
+pub fn run<S: Solution>(solution: &S, input: &str) -> Result<(), ParseError> {
+    let start = Instant::now();
+    let input = solution.parse(input)?;
+    let elapsed = start.elapsed();
+    println!("Parsed input, took {elapsed:?}");
+
+    let start = Instant::now();
+    let answer = solution.part_one(&input);
+    let elapsed = start.elapsed();
+    println!("Part one: {answer}, took {elapsed:?}");
+
+    let start = Instant::now();
+    let answer = solution.part_two(&input);
+    let elapsed = start.elapsed();
+    println!("Part two: {answer}, took {elapsed:?}");
+
+    Ok(())
+}
+
+/// Entry point for a day's binary: loads the input picked on the command line and runs both
+/// parts on it.
+pub fn main<S: Solution>(solution: &S, puzzle: Puzzle) {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+
+    let options = match InputOptions::extract(&mut args) {
+        Ok(options) => options,
+        Err(error) => usage_error(&puzzle, "", &error),
+    };
+
+    if let Some(arg) = args.first() {
+        usage_error(&puzzle, "", &format!("unexpected argument `{arg}`"));
+    }
+
+    let input = load_or_exit(&options, &puzzle);
+    run_or_exit(solution, &input);
+}
+
+/// Runs both parts, exiting with the rendered error if the input doesn't parse.
+pub fn run_or_exit<S: Solution>(solution: &S, input: &str) {
+    if let Err(error) = run(solution, input) {
+        eprintln!("{}", error.render(input));
+        process::exit(1);
+    }
+}
+
+/// Parses the input without solving it, exiting with the rendered error if it doesn't parse.
+pub fn parse_or_exit<'src, S: Solution>(solution: &S, input: &'src str) -> S::Input<'src> {
+    solution.parse(input).unwrap_or_else(|error| {
+        eprintln!("{}", error.render(input));
+        process::exit(1);
+    })
+}
+
+/// Loads the input, exiting with the error if it can't be read.
+pub fn load_or_exit(options: &InputOptions, puzzle: &Puzzle) -> String {
+    options.load(puzzle).unwrap_or_else(|error| {
+        eprintln!("error: {error}");
+        process::exit(1);
+    })
+}
+
+/// Exits after printing the error and the options every day's binary accepts, followed by the
+/// day's own `options`.
+pub fn usage_error(puzzle: &Puzzle, options: &str, error: &str) -> ! {
+    eprintln!(
+        "error: {error}\nusage: {} [--input <path|->] [--inputs-dir <dir>]{}{options}",
+        puzzle.name,
+        if options.is_empty() { "" } else { " " },
+    );
+    process::exit(2);
+}
@@ -0,0 +1,197 @@
+use std::{fmt::Display, num::ParseIntError, str::FromStr};
+
+/// A stretch of the puzzle input, with the line and column counted from 1 like an editor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub len: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// Something other than the expected token was found.
+    Unexpected {
+        span: Span,
+        expected: String,
+        found: String,
+    },
+    /// A number was found but doesn't fit the type it's parsed into.
+    InvalidNumber { span: Span, error: ParseIntError },
+}
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::Unexpected { span, .. } | ParseError::InvalidNumber { span, .. } => *span,
+        }
+    }
+
+    /// Formats the error with the offending line of `source` and the span underlined.
+    pub fn render(&self, source: &str) -> String {
+        let span = self.span();
+        let text = source.lines().nth(span.line - 1).unwrap_or_default();
+        let gutter = " ".repeat(span.line.to_string().len());
+
+        format!(
+            "error: {}\n{gutter}--> line {}, column {}\n{gutter} |\n{} | {text}\n{gutter} | {}{}",
+            self.message(),
+            span.line,
+            span.column,
+            span.line,
+            " ".repeat(span.column - 1),
+            "^".repeat(span.len.max(1)),
+        )
+    }
+
+    fn message(&self) -> String {
+        match self {
+            ParseError::Unexpected {
+                expected, found, ..
+            } => format!("expected {expected}, found {found}"),
+            ParseError::InvalidNumber { error, .. } => format!("invalid number: {error}"),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let span = self.span();
+        write!(
+            f,
+            "line {}, column {}: {}",
+            span.line,
+            span.column,
+            self.message()
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The whole puzzle input, used to locate the tokens sliced out of it when reporting errors.
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'src> {
+    text: &'src str,
+}
+
+impl<'src> Source<'src> {
+    pub fn new(text: &'src str) -> Self {
+        Self { text }
+    }
+
+    /// Where `token` is in the input. `token` must be a slice of the input.
+    pub fn span(&self, token: &str) -> Span {
+        let before = &self.text[..self.offset(token)];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+
+        Span {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            len: token.chars().count(),
+        }
+    }
+
+    /// An error for finding `token` where `expected` should have been. An empty `token` reports
+    /// whatever comes after it instead.
+    pub fn unexpected(&self, token: &str, expected: impl Display) -> ParseError {
+        let rest = &self.text[self.offset(token)..];
+
+        let found = if !token.is_empty() {
+            format!("`{token}`")
+        } else if rest.trim().is_empty() {
+            String::from("end of input")
+        } else if rest.starts_with(['\r', '\n']) {
+            String::from("end of line")
+        } else {
+            format!("`{}`", rest.chars().next().unwrap())
+        };
+
+        ParseError::Unexpected {
+            span: self.span(token),
+            expected: expected.to_string(),
+            found,
+        }
+    }
+
+    /// An error for `expected` being missing straight after `token`.
+    pub fn missing_after(&self, token: &str, expected: impl Display) -> ParseError {
+        self.unexpected(&token[token.len()..], expected)
+    }
+
+    pub fn number<T: FromStr<Err = ParseIntError>>(&self, token: &str) -> Result<T, ParseError> {
+        if token.is_empty() || !token.chars().all(|ch| ch.is_ascii_digit()) {
+            return Err(self.unexpected(token, "a number"));
+        }
+
+        token.parse().map_err(|error| ParseError::InvalidNumber {
+            span: self.span(token),
+            error,
+        })
+    }
+
+    fn offset(&self, token: &str) -> usize {
+        (token.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|offset| offset + token.len() <= self.text.len())
+            .expect("token should be a slice of the source")
+    }
+
+    pub fn split_once<'a>(
+        &self,
+        text: &'a str,
+        delimiter: char,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(delimiter)
+            .ok_or_else(|| self.missing_after(text, format_args!("`{delimiter}`")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_parse_error() {
+        let input = "11-22\n95-1x5\n";
+        let source = Source::new(input);
+        let (_start, end) = input.lines().nth(1).unwrap().split_once('-').unwrap();
+
+        let error = source.number::<u64>(end).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected a number, found `1x5`"
+        );
+        assert_eq!(
+            error.render(input),
+            "error: expected a number, found `1x5`
+ --> line 2, column 4
+  |
+2 | 95-1x5
+  |    ^^^"
+        );
+    }
+
+    #[test]
+    fn missing_delimiter() {
+        let input = "11-22\n95";
+        let source = Source::new(input);
+        let line = input.lines().nth(1).unwrap();
+
+        let error = source.split_once(line, '-').unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::Unexpected {
+                span: Span {
+                    line: 2,
+                    column: 3,
+                    len: 0
+                },
+                expected: String::from("`-`"),
+                found: String::from("end of input"),
+            }
+        );
+    }
+}
@@ -0,0 +1,28 @@
+/// A tiny deterministic random number generator for the tests that check a solution against
+/// a brute force on lots of generated inputs. Every run sees the same numbers, so a failure
+/// always reproduces.
+#[derive(Debug, Clone)]
+pub struct XorShift {
+    state: u32,
+}
+
+impl XorShift {
+    /// The seed can be anything but zero, which would only ever give zeroes.
+    pub fn new(seed: u32) -> Self {
+        assert_ne!(seed, 0, "a xorshift generator can't be seeded with zero");
+        Self { state: seed }
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 17;
+        self.state ^= self.state << 5;
+        self.state
+    }
+}
+
+impl Default for XorShift {
+    fn default() -> Self {
+        Self::new(0x2545_f491)
+    }
+}
@@ -0,0 +1,288 @@
+use std::{fmt::Display, slice};
+
+use common::{ParseError, Solution, Source};
+
+#[derive(Debug, Default)]
+pub struct Day01 {
+    pub dial: Dial,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    pub direction: Direction,
+    pub count: u64,
+}
+
+impl Rotation {
+    fn parse(source: &Source, line: &str) -> Result<Self, ParseError> {
+        let (direction, count) = line.split_at(line.chars().next().map_or(0, char::len_utf8));
+
+        let direction = match direction {
+            "R" => Direction::Right,
+            "L" => Direction::Left,
+            _ => return Err(source.unexpected(direction, "`R` or `L`")),
+        };
+
+        Ok(Self {
+            direction,
+            count: source.number(count)?,
+        })
+    }
+}
+
+impl Display for Rotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let direction = match self.direction {
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+
+        write!(f, "{direction}{}", self.count)
+    }
+}
+
+/// A dial numbered `0..size` that starts pointing at `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    size: u64,
+    start: u64,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Self::new(100, 50)
+    }
+}
+
+impl Dial {
+    /// # Panics
+    ///
+    /// If `size` is zero or `start` isn't on the dial.
+    pub fn new(size: u64, start: u64) -> Self {
+        assert!(
+            start < size,
+            "the dial starts at {start} but only has {size} clicks"
+        );
+        Self { size, start }
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// The state of the dial after each rotation in turn.
+    pub fn turns<'a>(&self, rotations: &'a [Rotation]) -> Turns<'a> {
+        Turns {
+            size: self.size,
+            position: self.start,
+            rotations: rotations.iter(),
+        }
+    }
+}
+
+/// Where the dial is after a rotation, and what happened on the way there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct State {
+    pub rotation: Rotation,
+    /// Where the dial points once the rotation is done.
+    pub position: u64,
+    /// How many times the dial went past the point between the last click and zero.
+    pub wraps: u64,
+    /// How many clicks of the rotation landed on zero, including the last.
+    pub zero_hits: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Turns<'a> {
+    size: u64,
+    position: u64,
+    rotations: slice::Iter<'a, Rotation>,
+}
+
+impl Iterator for Turns<'_> {
+    type Item = State;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rotation = *self.rotations.next()?;
+        let size = i128::from(self.size);
+        let position = i128::from(self.position);
+        let count = i128::from(rotation.count);
+
+        // How far the dial is from zero in the direction it's turning. Every full turn past
+        // that distance lands on zero once more.
+        let (moved, to_zero) = match rotation.direction {
+            Direction::Right => (position + count, (size - position) % size),
+            Direction::Left => (position - count, position),
+        };
+
+        let zero_hits = if to_zero == 0 {
+            count / size
+        } else if count >= to_zero {
+            (count - to_zero) / size + 1
+        } else {
+            0
+        };
+
+        self.position = moved.rem_euclid(size) as u64;
+
+        Some(State {
+            rotation,
+            position: self.position,
+            wraps: moved.div_euclid(size).unsigned_abs() as u64,
+            zero_hits: zero_hits as u64,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rotations.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Turns<'_> {}
+
+impl Solution for Day01 {
+    type Input<'src> = Vec<Rotation>;
+    type PartOne = usize;
+    type PartTwo = u64;
+
+    fn parse<'src>(&self, input: &'src str) -> Result<Self::Input<'src>, ParseError> {
+        let source = Source::new(input);
+        input
+            .lines()
+            .map(|line| Rotation::parse(&source, line))
+            .collect()
+    }
+
+    fn part_one(&self, rotations: &Self::Input<'_>) -> usize {
+        self.dial
+            .turns(rotations)
+            .filter(|state| state.position == 0)
+            .count()
+    }
+
+    fn part_two(&self, rotations: &Self::Input<'_>) -> u64 {
+        self.dial
+            .turns(rotations)
+            .map(|state| state.zero_hits)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_one_part_one() {
+        let input = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+        let day = Day01::default();
+        assert_eq!(day.part_one(&day.parse(input).unwrap()), 3);
+    }
+
+    #[test]
+    fn day_one_part_two() {
+        let input = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+        let day = Day01::default();
+        assert_eq!(day.part_two(&day.parse(input).unwrap()), 6);
+    }
+
+    /// Turns the dial one click at a time, counting every click that lands on zero.
+    fn part_two_brute_force(rotations: &[Rotation]) -> u64 {
+        let mut result = 0;
+        let mut dial: i64 = 50;
+
+        for rotation in rotations {
+            let step = match rotation.direction {
+                Direction::Right => 1,
+                Direction::Left => -1,
+            };
+
+            for _ in 0..rotation.count {
+                dial = (dial + step).rem_euclid(100);
+                if dial == 0 {
+                    result += 1;
+                }
+            }
+        }
+
+        result
+    }
+
+    #[test]
+    fn day_one_part_two_matches_brute_force() {
+        let mut rng = common::XorShift::default();
+        let mut next = || rng.next_u32();
+
+        for _ in 0..100 {
+            let rotations: Vec<_> = (0..50)
+                .map(|_| Rotation {
+                    direction: if next() % 2 == 0 {
+                        Direction::Left
+                    } else {
+                        Direction::Right
+                    },
+                    count: match next() % 3 {
+                        0 => u64::from(next() % 5) * 100,
+                        1 => u64::from(next() % 100),
+                        _ => u64::from(next() % 1000),
+                    },
+                })
+                .collect();
+
+            assert_eq!(
+                Day01::default().part_two(&rotations),
+                part_two_brute_force(&rotations),
+                "{rotations:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn day_one_part_two_large_rotation() {
+        let rotations = Day01::default().parse("R1000000000\nL1000000050").unwrap();
+
+        assert_eq!(Day01::default().part_two(&rotations), 20_000_001);
+    }
+
+    #[test]
+    fn dial_turns() {
+        let rotations = Day01::default().parse("L5\nR12\nL27\nR2").unwrap();
+
+        let states: Vec<_> = Dial::new(10, 3)
+            .turns(&rotations)
+            .map(|state| (state.position, state.wraps, state.zero_hits))
+            .collect();
+
+        assert_eq!(states, [(8, 1, 1), (0, 2, 2), (3, 3, 2), (5, 0, 0)]);
+    }
+}
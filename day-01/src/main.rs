@@ -1,110 +1,144 @@
-use std::time::Instant;
-
-fn main() {
-    let input = include_str!("../input.txt");
-
-    let start = Instant::now();
-    let answer = part_one(input);
-    let elapsed = start.elapsed();
-    println!("Part one: {}, took {}ms", answer, elapsed.as_millis());
-
-    let start = Instant::now();
-    let answer = part_two(input);
-    let elapsed = start.elapsed();
-    println!("Part two: {}, took {}ms", answer, elapsed.as_millis());
+use std::{
+    env,
+    fs::File,
+    io::{self, BufWriter, Write},
+    process,
+};
+
+use common::InputOptions;
+use day_01::{Day01, Dial, State};
+
+const OPTIONS: &str = "[--size <clicks>] [--start <click>] [--trace] [--trace-csv <path|->]";
+
+#[derive(Debug)]
+enum Trace {
+    /// A table of every rotation's state on stdout.
+    Table,
+    /// Every rotation's state as CSV, written to a file or stdout for `-`.
+    Csv(String),
 }
 
-fn part_one(input: &str) -> u64 {
-    let mut result = 0;
-    let mut dial: i32 = 50;
-
-    for line in input.lines() {
-        let (direction, count) = line.split_at(1);
-        let count: i32 = count.parse().expect("count should be a number");
+#[derive(Debug)]
+struct Options {
+    size: u64,
+    start: u64,
+    trace: Option<Trace>,
+}
 
-        match direction {
-            "R" => dial += count,
-            "L" => dial -= count,
-            _ => unreachable!("direction should be `R` or `L`"),
+impl Options {
+    fn parse(args: Vec<String>) -> Result<Self, String> {
+        let dial = Dial::default();
+        let mut options = Self {
+            size: dial.size(),
+            start: dial.start(),
+            trace: None,
+        };
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--size" => options.size = number(&arg, args.next())?,
+                "--start" => options.start = number(&arg, args.next())?,
+                "--trace" => options.trace = Some(Trace::Table),
+                "--trace-csv" => {
+                    let path = args.next().ok_or("`--trace-csv` needs a path")?;
+                    options.trace = Some(Trace::Csv(path));
+                }
+                _ => return Err(format!("unexpected argument `{arg}`")),
+            }
         }
 
-        dial %= 100;
-
-        if dial == 0 {
-            result += 1;
+        if options.start >= options.size {
+            return Err(format!(
+                "the dial starts at {} but only has {} clicks",
+                options.start, options.size
+            ));
         }
+
+        Ok(options)
     }
+}
 
-    result
+fn number(flag: &str, value: Option<String>) -> Result<u64, String> {
+    let value = value.ok_or_else(|| format!("`{flag}` needs a number"))?;
+    value
+        .parse()
+        .map_err(|_| format!("`{value}` is not a valid number for `{flag}`"))
 }
 
-fn part_two(input: &str) -> u64 {
-    let mut result = 0;
-    let mut dial: i32 = 50;
-
-    for line in input.lines() {
-        let (direction, count) = line.split_at(1);
-        let count: i32 = count.parse().expect("count should be a number");
-
-        match direction {
-            "R" => {
-                for _ in 0..count {
-                    dial += 1;
-                    dial %= 100;
-                    if dial == 0 {
-                        result += 1;
-                    }
-                }
-            }
-            "L" => {
-                for _ in 0..count {
-                    dial -= 1;
-                    dial %= 100;
-                    if dial == 0 {
-                        result += 1;
-                    }
-                }
-            }
-            _ => unreachable!("direction should be `R` or `L`"),
-        }
+fn main() {
+    let puzzle = common::puzzle!();
+    let mut args: Vec<String> = env::args().skip(1).collect();
+
+    let input_options = InputOptions::extract(&mut args)
+        .unwrap_or_else(|error| common::usage_error(&puzzle, OPTIONS, &error));
+    let options =
+        Options::parse(args).unwrap_or_else(|error| common::usage_error(&puzzle, OPTIONS, &error));
+
+    let day = Day01 {
+        dial: Dial::new(options.size, options.start),
+    };
+    let input = common::load_or_exit(&input_options, &puzzle);
+
+    let Some(trace) = options.trace else {
+        common::run_or_exit(&day, &input);
+        return;
+    };
+
+    let rotations = common::parse_or_exit(&day, &input);
+    let states = day.dial.turns(&rotations);
+
+    let result = match trace {
+        Trace::Table => write_table(&mut io::stdout().lock(), day.dial, states),
+        Trace::Csv(path) if path == "-" => write_csv(&mut io::stdout().lock(), states),
+        Trace::Csv(path) => File::create(&path)
+            .and_then(|file| write_csv(&mut BufWriter::new(file), states))
+            .map_err(|error| io::Error::new(error.kind(), format!("`{path}`: {error}"))),
+    };
+
+    if let Err(error) = result {
+        eprintln!("error: couldn't write the trace to {error}");
+        process::exit(1);
     }
-
-    result
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn day_one_part_one() {
-        let input = "L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82";
-
-        assert_eq!(part_one(input), 3);
+fn write_table(
+    out: &mut impl Write,
+    dial: Dial,
+    states: impl Iterator<Item = State>,
+) -> io::Result<()> {
+    writeln!(out, "Step  Rotation  Position  Wraps  Zero hits")?;
+    writeln!(out, "{:>4}  {:>8}  {:>8}", 0, "", dial.start())?;
+
+    for (step, state) in states.enumerate() {
+        writeln!(
+            out,
+            "{:>4}  {:>8}  {:>8}  {:>5}  {:>9}",
+            step + 1,
+            state.rotation.to_string(),
+            state.position,
+            state.wraps,
+            state.zero_hits
+        )?;
     }
 
-    #[test]
-    fn day_one_part_two() {
-        let input = "L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82";
-
-        assert_eq!(part_two(input), 6);
+    out.flush()
+}
+
+fn write_csv(out: &mut impl Write, states: impl Iterator<Item = State>) -> io::Result<()> {
+    writeln!(out, "step,rotation,position,wraps,zero_hits")?;
+
+    for (step, state) in states.enumerate() {
+        writeln!(
+            out,
+            "{},{},{},{},{}",
+            step + 1,
+            state.rotation,
+            state.position,
+            state.wraps,
+            state.zero_hits
+        )?;
     }
+
+    out.flush()
 }
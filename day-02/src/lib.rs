@@ -0,0 +1,450 @@
+use std::{
+    any,
+    fmt::{Debug, Display},
+    marker::PhantomData,
+    num::ParseIntError,
+    ops::{Add, Div, Mul, RangeInclusive, Rem, Sub},
+};
+
+use common::{ParseError, Solution, Source};
+
+/// Sums invalid IDs written in base `radix`, held in and summed as `T`.
+#[derive(Debug, Clone, Copy)]
+pub struct Day02<T = u64> {
+    radix: u32,
+    id: PhantomData<T>,
+}
+
+impl<T> Default for Day02<T> {
+    fn default() -> Self {
+        Self::with_radix(10)
+    }
+}
+
+impl<T> Day02<T> {
+    /// # Panics
+    ///
+    /// If `radix` isn't between 2 and 36.
+    pub fn with_radix(radix: u32) -> Self {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be between 2 and 36, not {radix}"
+        );
+
+        Self {
+            radix,
+            id: PhantomData,
+        }
+    }
+
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+}
+
+/// An unsigned integer type that IDs and their sums are held in.
+pub trait Id:
+    Copy
+    + Ord
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn from_u32(value: u32) -> Self;
+
+    fn from_str_radix(text: &str, radix: u32) -> Result<Self, ParseIntError>;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_id {
+    ($($int:ty),*) => {$(
+        impl Id for $int {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn from_u32(value: u32) -> Self {
+                value.into()
+            }
+
+            fn from_str_radix(text: &str, radix: u32) -> Result<Self, ParseIntError> {
+                <$int>::from_str_radix(text, radix)
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                self.checked_add(other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                self.checked_mul(other)
+            }
+        }
+    )*};
+}
+
+impl_id!(u32, u64, u128);
+
+/// A sum that didn't fit in the answer type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    pub type_name: &'static str,
+}
+
+impl Overflow {
+    fn of<T>() -> Self {
+        Self {
+            type_name: any::type_name::<T>(),
+        }
+    }
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the sum doesn't fit in a `{}`", self.type_name)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// The sum of the invalid IDs, or the overflow that stopped it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Total<T>(pub Result<T, Overflow>);
+
+impl<T: Display> Display for Total<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Ok(total) => write!(f, "{total}"),
+            Err(error) => write!(f, "error: {error}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Range<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Id> Range<T> {
+    fn parse(source: &Source, range: &str, radix: u32) -> Result<Self, ParseError> {
+        let (start, end) = source.split_once(range, '-')?;
+
+        Ok(Range {
+            start: number(source, start, radix)?,
+            end: number(source, end, radix)?,
+        })
+    }
+
+    /// Sums the IDs in the range that are `length` digits long and made of a block of
+    /// `block_length` digits repeated.
+    ///
+    /// Those IDs are exactly the blocks times a multiplier like `1001001` (a three digit block
+    /// repeated three times), so the blocks that land in the range form a run of consecutive
+    /// numbers that can be summed directly.
+    fn repeated_sum(&self, radix: T, length: u32, block_length: u32) -> Result<T, Overflow> {
+        // The smallest `length` digit ID fits, as one is in the range, and so do the shorter
+        // powers. Only the multiplier and the largest `length` digit ID might not.
+        let block_base = pow(radix, block_length).unwrap();
+
+        let Some(multiplier) = (1..length / block_length).try_fold(T::ONE, |multiplier, _| {
+            multiplier.checked_mul(block_base)?.checked_add(T::ONE)
+        }) else {
+            // Even the smallest block times the multiplier is too big to be in the range.
+            return Ok(T::ZERO);
+        };
+
+        let low = self.start.max(pow(radix, length - 1).unwrap());
+        let high = pow(radix, length).map_or(self.end, |limit| self.end.min(limit - T::ONE));
+
+        let first_block = div_ceil(low, multiplier).max(pow(radix, block_length - 1).unwrap());
+        let last_block = (high / multiplier).min(block_base - T::ONE);
+
+        if first_block > last_block {
+            return Ok(T::ZERO);
+        }
+
+        // `first + (first + 1) + ... + last`, arranged so no step is bigger than the result.
+        let count = last_block - first_block + T::ONE;
+        let two = T::from_u32(2);
+        let steps = if count % two == T::ZERO {
+            count / two * (count - T::ONE)
+        } else {
+            (count - T::ONE) / two * count
+        };
+
+        first_block
+            .checked_mul(count)
+            .and_then(|sum| sum.checked_add(steps))
+            .and_then(|sum| sum.checked_mul(multiplier))
+            .ok_or(Overflow::of::<T>())
+    }
+
+    /// The digit counts of the IDs in the range.
+    fn lengths(&self, radix: T) -> RangeInclusive<u32> {
+        digits(self.start, radix)..=digits(self.end, radix)
+    }
+}
+
+fn number<T: Id>(source: &Source, token: &str, radix: u32) -> Result<T, ParseError> {
+    if token.is_empty() || !token.chars().all(|ch| ch.is_digit(radix)) {
+        return Err(if radix == 10 {
+            source.unexpected(token, "a number")
+        } else {
+            source.unexpected(token, format_args!("a base {radix} number"))
+        });
+    }
+
+    T::from_str_radix(token, radix).map_err(|error| ParseError::InvalidNumber {
+        span: source.span(token),
+        error,
+    })
+}
+
+fn pow<T: Id>(base: T, exponent: u32) -> Option<T> {
+    (0..exponent).try_fold(T::ONE, |power, _| power.checked_mul(base))
+}
+
+fn div_ceil<T: Id>(dividend: T, divisor: T) -> T {
+    let quotient = dividend / divisor;
+    if dividend % divisor == T::ZERO {
+        quotient
+    } else {
+        quotient + T::ONE
+    }
+}
+
+fn digits<T: Id>(mut id: T, radix: T) -> u32 {
+    let mut digits = 1;
+    while id >= radix {
+        id = id / radix;
+        digits += 1;
+    }
+    digits
+}
+
+impl<T: Id> Day02<T> {
+    fn total(
+        &self,
+        ranges: &[Range<T>],
+        mut sum: impl FnMut(&Range<T>, T, u32) -> Result<T, Overflow>,
+    ) -> Total<T> {
+        let radix = T::from_u32(self.radix);
+
+        Total(ranges.iter().try_fold(T::ZERO, |total, range| {
+            range.lengths(radix).try_fold(total, |total, length| {
+                total
+                    .checked_add(sum(range, radix, length)?)
+                    .ok_or(Overflow::of::<T>())
+            })
+        }))
+    }
+}
+
+impl<T: Id> Solution for Day02<T> {
+    type Input<'src> = Vec<Range<T>>;
+    type PartOne = Total<T>;
+    type PartTwo = Total<T>;
+
+    fn parse<'src>(&self, input: &'src str) -> Result<Self::Input<'src>, ParseError> {
+        let source = Source::new(input);
+        input
+            .trim_end()
+            .split(',')
+            .map(|range| Range::parse(&source, range, self.radix))
+            .collect()
+    }
+
+    fn part_one(&self, ranges: &Self::Input<'_>) -> Total<T> {
+        self.total(ranges, |range, radix, length| {
+            if length % 2 == 0 {
+                range.repeated_sum(radix, length, length / 2)
+            } else {
+                Ok(T::ZERO)
+            }
+        })
+    }
+
+    fn part_two(&self, ranges: &Self::Input<'_>) -> Total<T> {
+        self.total(ranges, |range, radix, length| {
+            // An ID made of a repeated block of `block_length` digits is also made of repeated
+            // blocks of every multiple of the shortest such block that divides `length`, so
+            // summing per block length would count it several times. Instead, take away the IDs
+            // whose shortest block is a proper divisor of the block length, which leaves the IDs
+            // whose shortest block is exactly that length: these don't overlap and none of them
+            // is bigger than the answer, so an overflow only happens if the answer overflows.
+            let block_lengths: Vec<u32> = (1..length).filter(|b| length % b == 0).collect();
+            let mut shortest: Vec<T> = Vec::with_capacity(block_lengths.len());
+            let mut total = T::ZERO;
+
+            for (i, &block_length) in block_lengths.iter().enumerate() {
+                let repeated = range.repeated_sum(radix, length, block_length)?;
+                let shorter = block_lengths[..i]
+                    .iter()
+                    .zip(&shortest)
+                    .filter(|&(&divisor, _)| block_length % divisor == 0)
+                    .fold(T::ZERO, |sum, (_, &sum_of_shorter)| sum + sum_of_shorter);
+
+                shortest.push(repeated - shorter);
+                total = total
+                    .checked_add(repeated - shorter)
+                    .ok_or(Overflow::of::<T>())?;
+            }
+
+            Ok(total)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_two_part_one() {
+        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+        let day = Day02::<u64>::default();
+
+        assert_eq!(
+            day.part_one(&day.parse(input).unwrap()),
+            Total(Ok(1227775554))
+        );
+    }
+
+    #[test]
+    fn day_two_part_two() {
+        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+        let day = Day02::<u64>::default();
+
+        assert_eq!(
+            day.part_two(&day.parse(input).unwrap()),
+            Total(Ok(4174379265))
+        );
+    }
+
+    /// Checks every ID's digits for a repeated block, keeping those repeated `times` times or,
+    /// without `times`, any number of times.
+    fn brute_force(ranges: &[Range<u64>], radix: u64, times: Option<usize>) -> u64 {
+        let mut result = 0;
+
+        for range in ranges {
+            for id in range.start..=range.end {
+                let mut digits = Vec::new();
+                let mut rest = id;
+                loop {
+                    digits.push(rest % radix);
+                    rest /= radix;
+                    if rest == 0 {
+                        break;
+                    }
+                }
+
+                let len = digits.len();
+                let repeated = (1..len)
+                    .filter(|block| len % block == 0)
+                    .filter(|block| times.is_none_or(|times| len / block == times))
+                    .any(|block| digits.chunks(block).all(|chunk| chunk == &digits[..block]));
+
+                if repeated {
+                    result += id;
+                }
+            }
+        }
+
+        result
+    }
+
+    #[test]
+    fn day_two_matches_brute_force() {
+        let input = "1-100000,999990-1000010,123123-124124,1-1,10-10,5-2";
+        let day = Day02::<u64>::default();
+        let ranges = day.parse(input).unwrap();
+
+        assert_eq!(
+            day.part_one(&ranges),
+            Total(Ok(brute_force(&ranges, 10, Some(2))))
+        );
+        assert_eq!(
+            day.part_two(&ranges),
+            Total(Ok(brute_force(&ranges, 10, None)))
+        );
+    }
+
+    #[test]
+    fn day_two_other_radixes() {
+        for (radix, input) in [(2, "1-11111111111111111"), (7, "1-654321"), (36, "1-zzz")] {
+            let day = Day02::<u64>::with_radix(radix);
+            let ranges = day.parse(input).unwrap();
+            let radix = u64::from(radix);
+
+            assert_eq!(
+                day.part_one(&ranges),
+                Total(Ok(brute_force(&ranges, radix, Some(2))))
+            );
+            assert_eq!(
+                day.part_two(&ranges),
+                Total(Ok(brute_force(&ranges, radix, None)))
+            );
+        }
+    }
+
+    #[test]
+    fn day_two_huge_range() {
+        let day = Day02::<u128>::default();
+        let ranges = day.parse("1-9999999999999999999").unwrap();
+
+        // Every block of 1 to 9 digits, doubled.
+        let part_one: u128 = (1..10)
+            .map(|length| {
+                let multiplier = 10u128.pow(length) + 1;
+                let first = 10u128.pow(length - 1);
+                let last = 10u128.pow(length) - 1;
+                multiplier * (first + last) * (last - first + 1) / 2
+            })
+            .sum();
+
+        assert_eq!(day.part_one(&ranges), Total(Ok(part_one)));
+    }
+
+    #[test]
+    fn day_two_overflow() {
+        let day = Day02::<u64>::default();
+        let ranges = day.parse("1-9999999999999999999").unwrap();
+
+        assert_eq!(day.part_one(&ranges), Total(Err(Overflow::of::<u64>())));
+        assert_eq!(
+            day.part_two(&ranges).to_string(),
+            "error: the sum doesn't fit in a `u64`"
+        );
+    }
+
+    #[test]
+    fn day_two_parse_error() {
+        let error = Day02::<u64>::default()
+            .parse("11-22,95115,998-1012")
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 12: expected `-`, found `,`"
+        );
+    }
+
+    #[test]
+    fn day_two_radix_parse_error() {
+        let error = Day02::<u64>::with_radix(2).parse("1-12").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: expected a base 2 number, found `12`"
+        );
+    }
+}
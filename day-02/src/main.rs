@@ -1,97 +1,49 @@
-use std::{str::FromStr, time::Instant};
+use std::env;
 
-fn main() {
-    let input = include_str!("../input.txt");
-
-    let start = Instant::now();
-    let answer = part_one(input);
-    let elapsed = start.elapsed();
-    println!("Part one: {}, took {}ms", answer, elapsed.as_millis());
-
-    let start = Instant::now();
-    let answer = part_two(input);
-    let elapsed = start.elapsed();
-    println!("Part two: {}, took {}ms", answer, elapsed.as_millis());
-}
-
-struct Range {
-    start: u64,
-    end: u64,
-}
-
-impl FromStr for Range {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (start, end) = s.split_once('-').unwrap();
+use common::InputOptions;
+use day_02::Day02;
 
-        Ok(Range {
-            start: start.parse().unwrap(),
-            end: end.parse().unwrap(),
-        })
-    }
-}
-
-fn part_one(input: &str) -> u64 {
-    let mut result = 0;
-
-    for range in input.split(',') {
-        let range: Range = range.parse().unwrap();
+const OPTIONS: &str = "[--radix <2-36>] [--wide]";
 
-        for id in range.start..=range.end {
-            let id_string = id.to_string();
-
-            if id_string.len() % 2 == 0 {
-                let (left, right) = id_string.split_at(id_string.len() / 2);
-
-                if left == right {
-                    result += id;
-                }
-            }
-        }
-    }
-
-    result
-}
-
-fn part_two(input: &str) -> u64 {
-    let mut result = 0;
-
-    for range in input.split(',') {
-        let range: Range = range.parse().unwrap();
-
-        for id in range.start..=range.end {
-            let id_string = id.to_string();
-
-            for i in 1..=(id_string.len() / 2) {
-                let candidate = &id_string[0..i];
-
-                if id_string.len() % i == 0 && candidate.repeat(id_string.len() / i) == id_string {
-                    result += id;
-                    break;
-                }
+fn main() {
+    let puzzle = common::puzzle!();
+    let mut args: Vec<String> = env::args().skip(1).collect();
+
+    let input_options = InputOptions::extract(&mut args)
+        .unwrap_or_else(|error| common::usage_error(&puzzle, OPTIONS, &error));
+
+    let mut radix = 10;
+    let mut wide = false;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--radix" => {
+                let value = args.next().unwrap_or_else(|| {
+                    common::usage_error(&puzzle, OPTIONS, "`--radix` needs a value")
+                });
+                radix = value
+                    .parse()
+                    .ok()
+                    .filter(|radix| (2..=36).contains(radix))
+                    .unwrap_or_else(|| {
+                        common::usage_error(
+                            &puzzle,
+                            OPTIONS,
+                            &format!("`{value}` is not a radix between 2 and 36"),
+                        )
+                    });
             }
+            "--wide" => wide = true,
+            _ => common::usage_error(&puzzle, OPTIONS, &format!("unexpected argument `{arg}`")),
         }
     }
 
-    result
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn day_two_part_one() {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-
-        assert_eq!(part_one(input), 1227775554);
-    }
-
-    #[test]
-    fn day_two_part_two() {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+    let input = common::load_or_exit(&input_options, &puzzle);
 
-        assert_eq!(part_two(input), 4174379265);
+    if wide {
+        common::run_or_exit(&Day02::<u128>::with_radix(radix), &input);
+    } else {
+        common::run_or_exit(&Day02::<u64>::with_radix(radix), &input);
     }
 }
@@ -0,0 +1,241 @@
+use std::fmt::Display;
+
+use common::{ParseError, Solution, Source};
+
+#[derive(Debug, Default)]
+pub struct Day03;
+
+/// The batteries turned on in a bank, and the joltage their digits make in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Joltage {
+    /// Where each battery is in the bank, in ascending order.
+    pub indices: Vec<usize>,
+    pub digits: Vec<u8>,
+}
+
+impl Joltage {
+    /// The joltage as a number, or `None` when it has more digits than a `u128` holds. Up to
+    /// 38 digits always fit.
+    pub fn value(&self) -> Option<u128> {
+        self.digits.iter().try_fold(0u128, |value, &digit| {
+            value.checked_mul(10)?.checked_add(u128::from(digit))
+        })
+    }
+}
+
+impl Display for Joltage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for digit in &self.digits {
+            write!(f, "{digit}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Turns on the `k` batteries of `bank` that make the largest joltage, or `None` if the bank
+/// has fewer than `k` batteries.
+///
+/// Keeps the picked batteries on a stack, and whenever a battery beats the top of the stack and
+/// enough batteries are left to still pick `k`, drops the top: a bigger digit earlier always
+/// makes a bigger number. Every battery is pushed and popped at most once.
+pub fn max_joltage(bank: &[u8], k: usize) -> Option<Joltage> {
+    let mut droppable = bank.len().checked_sub(k)?;
+    let mut stack: Vec<usize> = Vec::with_capacity(bank.len());
+
+    for (i, &digit) in bank.iter().enumerate() {
+        while droppable > 0
+            && let Some(&top) = stack.last()
+            && bank[top] < digit
+        {
+            stack.pop();
+            droppable -= 1;
+        }
+
+        stack.push(i);
+    }
+
+    stack.truncate(k);
+
+    Some(Joltage {
+        digits: stack.iter().map(|&i| bank[i]).collect(),
+        indices: stack,
+    })
+}
+
+fn parse_bank(source: &Source, line: &str) -> Result<Vec<u8>, ParseError> {
+    line.char_indices()
+        .map(|(i, ch)| {
+            ch.to_digit(10)
+                .map(|digit| digit as u8)
+                .ok_or_else(|| source.unexpected(&line[i..i + ch.len_utf8()], "a digit"))
+        })
+        .collect()
+}
+
+/// Why the banks have no total joltage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoltageError {
+    /// The bank on `line` has fewer than `k` batteries to turn on.
+    ShortBank {
+        line: usize,
+        batteries: usize,
+        k: usize,
+    },
+    Overflow,
+}
+
+impl Display for JoltageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoltageError::ShortBank { line, batteries, k } => write!(
+                f,
+                "the bank on line {line} has {batteries} batteries, so it can't turn on {k}"
+            ),
+            JoltageError::Overflow => write!(f, "the total joltage doesn't fit in a `u128`"),
+        }
+    }
+}
+
+impl std::error::Error for JoltageError {}
+
+/// The total joltage, or why there isn't one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TotalJoltage(pub Result<u128, JoltageError>);
+
+impl Display for TotalJoltage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Ok(total) => write!(f, "{total}"),
+            Err(error) => write!(f, "error: {error}"),
+        }
+    }
+}
+
+/// Sums the largest joltage of `k` batteries from every bank.
+fn total_joltage(banks: &[Vec<u8>], k: usize) -> Result<u128, JoltageError> {
+    banks
+        .iter()
+        .enumerate()
+        .try_fold(0u128, |total, (i, bank)| {
+            let joltage = max_joltage(bank, k).ok_or(JoltageError::ShortBank {
+                line: i + 1,
+                batteries: bank.len(),
+                k,
+            })?;
+
+            joltage
+                .value()
+                .and_then(|value| total.checked_add(value))
+                .ok_or(JoltageError::Overflow)
+        })
+}
+
+impl Solution for Day03 {
+    type Input<'src> = Vec<Vec<u8>>;
+    type PartOne = TotalJoltage;
+    type PartTwo = TotalJoltage;
+
+    fn parse<'src>(&self, input: &'src str) -> Result<Self::Input<'src>, ParseError> {
+        let source = Source::new(input);
+        input
+            .lines()
+            .map(|line| parse_bank(&source, line))
+            .collect()
+    }
+
+    fn part_one(&self, banks: &Self::Input<'_>) -> TotalJoltage {
+        TotalJoltage(total_joltage(banks, 2))
+    }
+
+    fn part_two(&self, banks: &Self::Input<'_>) -> TotalJoltage {
+        TotalJoltage(total_joltage(banks, 12))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_three_part_one() {
+        let input = "987654321111111
+811111111111119
+234234234234278
+818181911112111";
+
+        assert_eq!(
+            Day03.part_one(&Day03.parse(input).unwrap()),
+            TotalJoltage(Ok(357))
+        );
+    }
+
+    #[test]
+    fn day_three_part_two() {
+        let input = "987654321111111
+811111111111119
+234234234234278
+818181911112111";
+
+        assert_eq!(
+            Day03.part_two(&Day03.parse(input).unwrap()),
+            TotalJoltage(Ok(3121910778619))
+        );
+    }
+
+    #[test]
+    fn max_joltage_indices() {
+        let bank = [8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1];
+
+        let joltage = max_joltage(&bank, 12).unwrap();
+
+        assert_eq!(joltage.to_string(), "888911112111");
+        assert_eq!(joltage.indices, [0, 2, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14]);
+        assert_eq!(max_joltage(&bank, 16), None);
+    }
+
+    #[test]
+    fn max_joltage_beyond_u128() {
+        let bank: Vec<u8> = (0..60).map(|i| (i * 7 % 10) as u8).collect();
+
+        let joltage = max_joltage(&bank, 40).unwrap();
+
+        assert_eq!(joltage.value(), None);
+        assert_eq!(
+            joltage.to_string(),
+            "9974852963074185296307418529630741852963"
+        );
+        assert_eq!(
+            max_joltage(&bank, 38).unwrap().value(),
+            Some(99852963074185296307418529630741852963)
+        );
+    }
+
+    #[test]
+    fn day_three_short_bank() {
+        let banks = Day03
+            .parse("987654321111111\n8111111\n234234234234278")
+            .unwrap();
+
+        assert_eq!(Day03.part_one(&banks), TotalJoltage(Ok(98 + 81 + 78)));
+        assert_eq!(
+            Day03.part_two(&banks).to_string(),
+            "error: the bank on line 2 has 7 batteries, so it can't turn on 12"
+        );
+    }
+
+    #[test]
+    fn day_three_overflow() {
+        let banks = vec![vec![9; 38]; 4];
+
+        assert_eq!(
+            total_joltage(&banks[..1], 38),
+            Ok(99999999999999999999999999999999999999)
+        );
+        assert_eq!(total_joltage(&banks, 38), Err(JoltageError::Overflow));
+        assert_eq!(
+            total_joltage(&[vec![9; 40]], 39),
+            Err(JoltageError::Overflow)
+        );
+    }
+}
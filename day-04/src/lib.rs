@@ -0,0 +1,333 @@
+use std::fmt::Display;
+
+use common::{ParseError, Solution, Source};
+use grid::{Grid, Position};
+
+pub mod render;
+
+#[derive(Debug, Default)]
+pub struct Day04 {
+    pub rule: Rule,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Roll,
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Cell::Empty => write!(f, "."),
+            Cell::Roll => write!(f, "@"),
+        }
+    }
+}
+
+/// What's left after removing every roll a forklift can reach, round by round.
+#[derive(Debug, Clone)]
+pub struct Removal {
+    /// The rolls removed in each round, which became reachable once the previous round's rolls
+    /// were gone.
+    pub rounds: Vec<Vec<Position>>,
+    /// The grid once no more rolls can be removed.
+    pub grid: Grid<Cell>,
+}
+
+impl Removal {
+    pub fn removed(&self) -> usize {
+        self.rounds.iter().map(Vec::len).sum()
+    }
+}
+
+/// Which cells count as a cell's neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// Up, down, left and right.
+    Four,
+    /// The orthogonal neighbours and the diagonals.
+    Eight,
+}
+
+impl Neighbourhood {
+    fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Neighbourhood::Four => &grid::ORTHOGONAL_OFFSETS,
+            Neighbourhood::Eight => &grid::ALL_OFFSETS,
+        }
+    }
+}
+
+/// When a forklift can reach a roll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    /// A roll can be reached if fewer than this many of its neighbours are rolls.
+    pub threshold: usize,
+    pub neighbourhood: Neighbourhood,
+    /// Whether the grid's edges wrap around, so every cell has a full neighbourhood.
+    pub wrap: bool,
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Self {
+            threshold: 4,
+            neighbourhood: Neighbourhood::Eight,
+            wrap: false,
+        }
+    }
+}
+
+impl Rule {
+    /// The distinct neighbours of `position`. On a wrapping grid smaller than the
+    /// neighbourhood, offsets can wrap round to the same cell or back to `position` itself, and
+    /// each of those cells only counts once, if at all.
+    fn neighbours(&self, grid: &Grid<Cell>, position: Position) -> impl Iterator<Item = Position> {
+        let mut neighbours: Vec<Position> = Vec::with_capacity(self.neighbourhood.offsets().len());
+
+        for &offset in self.neighbourhood.offsets() {
+            let neighbour = if self.wrap {
+                Some(grid.wrapping_offset(position, offset))
+            } else {
+                grid.offset(position, offset)
+            };
+
+            if let Some(neighbour) = neighbour
+                && neighbour != position
+                && !neighbours.contains(&neighbour)
+            {
+                neighbours.push(neighbour);
+            }
+        }
+
+        neighbours.into_iter()
+    }
+
+    fn neighbouring_rolls(&self, grid: &Grid<Cell>, position: Position) -> usize {
+        self.neighbours(grid, position)
+            .filter(|&neighbour| grid[neighbour] == Cell::Roll)
+            .count()
+    }
+}
+
+/// Removes reachable rolls until none are left.
+///
+/// Keeps a count of each roll's neighbouring rolls and only looks at a roll again when one of
+/// its neighbours is removed, so each roll is visited once per neighbour rather than once per
+/// round.
+pub fn remove_rolls(grid: &Grid<Cell>, rule: &Rule) -> Removal {
+    let mut grid = grid.clone();
+    let mut neighbours = grid.map(|_| 0);
+
+    for position in grid.positions() {
+        neighbours[position] = rule.neighbouring_rolls(&grid, position);
+    }
+
+    let mut round: Vec<Position> = grid
+        .iter()
+        .filter(|&(position, cell)| *cell == Cell::Roll && neighbours[position] < rule.threshold)
+        .map(|(position, _)| position)
+        .collect();
+    let mut rounds = Vec::new();
+
+    while !round.is_empty() {
+        for &position in &round {
+            grid[position] = Cell::Empty;
+        }
+
+        let mut next = Vec::new();
+
+        for &position in &round {
+            for neighbour in rule.neighbours(&grid, position) {
+                if grid[neighbour] == Cell::Roll {
+                    neighbours[neighbour] -= 1;
+
+                    // Only the removal that takes it below the limit queues it, so it's
+                    // queued once.
+                    if neighbours[neighbour] + 1 == rule.threshold {
+                        next.push(neighbour);
+                    }
+                }
+            }
+        }
+
+        rounds.push(round);
+        round = next;
+    }
+
+    Removal { rounds, grid }
+}
+
+impl Solution for Day04 {
+    type Input<'src> = Grid<Cell>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse<'src>(&self, input: &'src str) -> Result<Self::Input<'src>, ParseError> {
+        let source = Source::new(input);
+        Grid::parse(&source, input, |ch, token| match ch {
+            '@' => Ok(Cell::Roll),
+            '.' => Ok(Cell::Empty),
+            _ => Err(source.unexpected(token, "`@` or `.`")),
+        })
+    }
+
+    fn part_one(&self, grid: &Self::Input<'_>) -> u64 {
+        grid.iter()
+            .filter(|&(position, cell)| {
+                *cell == Cell::Roll
+                    && self.rule.neighbouring_rolls(grid, position) < self.rule.threshold
+            })
+            .count() as u64
+    }
+
+    fn part_two(&self, grid: &Self::Input<'_>) -> u64 {
+        remove_rolls(grid, &self.rule).removed() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_four_part_one() {
+        let input = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+        assert_eq!(
+            Day04::default().part_one(&Day04::default().parse(input).unwrap()),
+            13
+        );
+    }
+
+    #[test]
+    fn day_four_part_two() {
+        let input = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+        assert_eq!(
+            Day04::default().part_two(&Day04::default().parse(input).unwrap()),
+            43
+        );
+    }
+
+    #[test]
+    fn day_four_removal_rounds() {
+        let input = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+        let removal = remove_rolls(&Day04::default().parse(input).unwrap(), &Rule::default());
+
+        assert_eq!(
+            removal.rounds.iter().map(Vec::len).collect::<Vec<_>>(),
+            [13, 12, 7, 5, 2, 1, 1, 1, 1]
+        );
+        assert_eq!(
+            removal.grid.to_string(),
+            "..........
+..........
+..........
+....@@....
+...@@@@...
+...@@@@@..
+...@.@.@@.
+...@@.@@@.
+...@@@@@..
+....@@@...
+"
+        );
+    }
+
+    #[test]
+    fn day_four_other_rules() {
+        let grid = Day04::default().parse("@@@.\n@@@.\n@@@.\n....").unwrap();
+
+        let four = Day04 {
+            rule: Rule {
+                threshold: 3,
+                neighbourhood: Neighbourhood::Four,
+                wrap: false,
+            },
+        };
+        assert_eq!(four.part_one(&grid), 4);
+        assert_eq!(four.part_two(&grid), 9);
+    }
+
+    #[test]
+    fn day_four_wrapping() {
+        let grid = Day04::default().parse("@@@\n@@@\n@@@").unwrap();
+
+        let flat = Day04::default();
+        assert_eq!(flat.part_one(&grid), 4);
+        assert_eq!(flat.part_two(&grid), 9);
+
+        // On a torus every roll is surrounded.
+        let wrapping = Day04 {
+            rule: Rule {
+                wrap: true,
+                ..Rule::default()
+            },
+        };
+        assert_eq!(wrapping.part_one(&grid), 0);
+        assert_eq!(wrapping.part_two(&grid), 0);
+    }
+
+    #[test]
+    fn day_four_wrapping_thin_grids() {
+        let rule = Rule {
+            wrap: true,
+            ..Rule::default()
+        };
+        let neighbours = |grid: &Grid<Cell>, position| {
+            let mut neighbours: Vec<Position> = rule.neighbours(grid, position).collect();
+            neighbours.sort_unstable();
+            neighbours
+        };
+
+        // Every offset above or below wraps back into the only row.
+        let row = Day04::default().parse("@@@@@").unwrap();
+        assert_eq!(
+            neighbours(&row, Position::new(0, 0)),
+            [Position::new(0, 1), Position::new(0, 4)]
+        );
+
+        let column = Day04::default().parse("@\n@").unwrap();
+        assert_eq!(
+            neighbours(&column, Position::new(1, 0)),
+            [Position::new(0, 0)]
+        );
+
+        let cell = Day04::default().parse("@").unwrap();
+        assert_eq!(neighbours(&cell, Position::new(0, 0)), []);
+
+        // Each roll only has two rolls next to it, so they can all be reached.
+        let wrapping = Day04 { rule };
+        assert_eq!(wrapping.part_one(&row), 5);
+    }
+}
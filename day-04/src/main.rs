@@ -1,190 +1,163 @@
-use std::time::Instant;
+use std::{
+    env,
+    fs::{self, File},
+    io::BufWriter,
+    path::PathBuf,
+    process, thread,
+    time::Duration,
+};
+
+use common::InputOptions;
+use day_04::{Day04, Neighbourhood, Rule, remove_rolls, render};
+
+const OPTIONS: &str = "[--threshold <rolls>] [--neighbours <4|8>] [--wrap] [--animate] \
+                       [--frames <dir>] [--gif <path>] [--delay <ms>] [--scale <pixels>]";
+
+#[derive(Debug)]
+struct Options {
+    rule: Rule,
+    /// Play the rounds in the terminal.
+    animate: bool,
+    /// Write a PPM image of each round to this directory.
+    frames: Option<PathBuf>,
+    /// Write an animated GIF of the rounds to this path.
+    gif: Option<PathBuf>,
+    /// How long each round is shown for.
+    delay: Duration,
+    /// The size of a cell in pixels.
+    scale: usize,
+}
 
-fn main() {
-    let input = include_str!("../input.txt");
+impl Options {
+    fn parse(args: Vec<String>) -> Result<Self, String> {
+        let mut options = Self {
+            rule: Rule::default(),
+            animate: false,
+            frames: None,
+            gif: None,
+            delay: Duration::from_millis(300),
+            scale: 8,
+        };
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--threshold" => options.rule.threshold = number(&arg, args.next())?,
+                "--neighbours" => {
+                    options.rule.neighbourhood = match args.next().as_deref() {
+                        Some("4") => Neighbourhood::Four,
+                        Some("8") => Neighbourhood::Eight,
+                        Some(value) => return Err(format!("`{value}` is not 4 or 8 neighbours")),
+                        None => return Err(String::from("`--neighbours` needs 4 or 8")),
+                    };
+                }
+                "--wrap" => options.rule.wrap = true,
+                "--animate" => options.animate = true,
+                "--frames" => {
+                    let dir = args.next().ok_or("`--frames` needs a directory")?;
+                    options.frames = Some(PathBuf::from(dir));
+                }
+                "--gif" => {
+                    let path = args.next().ok_or("`--gif` needs a path")?;
+                    options.gif = Some(PathBuf::from(path));
+                }
+                "--delay" => options.delay = Duration::from_millis(number(&arg, args.next())?),
+                "--scale" => {
+                    options.scale = number(&arg, args.next())?;
+                    if options.scale == 0 {
+                        return Err(String::from("`--scale` must be at least 1"));
+                    }
+                }
+                _ => return Err(format!("unexpected argument `{arg}`")),
+            }
+        }
 
-    let start = Instant::now();
-    let answer = part_one(input);
-    let elapsed = start.elapsed();
-    println!("Part one: {}, took {}ms", answer, elapsed.as_millis());
+        if options.gif.is_some() && !cfg!(feature = "gif") {
+            return Err(String::from(
+                "`--gif` needs day-04 to be built with `--features gif`",
+            ));
+        }
 
-    let start = Instant::now();
-    let answer = part_two(input);
-    let elapsed = start.elapsed();
-    println!("Part two: {}, took {}ms", answer, elapsed.as_millis());
+        Ok(options)
+    }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-enum Cell {
-    Empty,
-    Roll,
+fn number<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("`{flag}` needs a number"))?;
+    value
+        .parse()
+        .map_err(|_| format!("`{value}` is not a valid number for `{flag}`"))
 }
 
-const DIRECTIONS: [(isize, isize); 8] = [
-    (-1, 0),
-    (-1, 1),
-    (0, 1),
-    (1, 1),
-    (1, 0),
-    (1, -1),
-    (0, -1),
-    (-1, -1),
-];
-
-fn part_one(input: &str) -> u64 {
-    let grid: Vec<Vec<Cell>> = input
-        .lines()
-        .map(|row| {
-            row.chars()
-                .map(|ch| match ch {
-                    '@' => Cell::Roll,
-                    '.' => Cell::Empty,
-                    _ => unreachable!(),
-                })
-                .collect()
-        })
-        .collect();
-
-    let rows = grid.len();
-    let cols = grid[0].len();
-
-    let mut result = 0;
-
-    for row_index in 0..rows {
-        for col_index in 0..cols {
-            if grid[row_index][col_index] == Cell::Roll {
-                let mut adjacent_rolls = 0;
-                for (row_offset, col_offset) in DIRECTIONS {
-                    let new_row = row_index as isize + row_offset;
-                    if new_row < 0 {
-                        continue;
-                    }
+fn main() {
+    let puzzle = common::puzzle!();
+    let mut args: Vec<String> = env::args().skip(1).collect();
 
-                    let new_col = col_index as isize + col_offset;
-                    if new_col < 0 {
-                        continue;
-                    }
+    let input_options = InputOptions::extract(&mut args)
+        .unwrap_or_else(|error| common::usage_error(&puzzle, OPTIONS, &error));
+    let options =
+        Options::parse(args).unwrap_or_else(|error| common::usage_error(&puzzle, OPTIONS, &error));
 
-                    if let Some(row) = grid.get(new_row as usize)
-                        && let Some(cell) = row.get(new_col as usize)
-                    {
-                        match cell {
-                            Cell::Empty => {}
-                            Cell::Roll => {
-                                adjacent_rolls += 1;
-                            }
-                        }
-                    }
-                }
-                if adjacent_rolls < 4 {
-                    result += 1;
-                }
-            }
-        }
-    }
+    let day = Day04 { rule: options.rule };
+    let input = common::load_or_exit(&input_options, &puzzle);
 
-    result
-}
+    if !options.animate && options.frames.is_none() && options.gif.is_none() {
+        common::run_or_exit(&day, &input);
+        return;
+    }
 
-fn part_two(input: &str) -> u64 {
-    let mut grid: Vec<Vec<Cell>> = input
-        .lines()
-        .map(|row| {
-            row.chars()
-                .map(|ch| match ch {
-                    '@' => Cell::Roll,
-                    '.' => Cell::Empty,
-                    _ => unreachable!(),
-                })
-                .collect()
-        })
-        .collect();
-
-    let rows = grid.len();
-    let cols = grid[0].len();
-
-    let mut result = 0;
-
-    loop {
-        let mut stop = true;
-
-        for row_index in 0..rows {
-            for col_index in 0..cols {
-                if grid[row_index][col_index] == Cell::Roll {
-                    let mut adjacent_rolls = 0;
-                    for (row_offset, col_offset) in DIRECTIONS {
-                        let new_row = row_index as isize + row_offset;
-                        if new_row < 0 {
-                            continue;
-                        }
-
-                        let new_col = col_index as isize + col_offset;
-                        if new_col < 0 {
-                            continue;
-                        }
-
-                        if let Some(row) = grid.get(new_row as usize)
-                            && let Some(cell) = row.get(new_col as usize)
-                        {
-                            match cell {
-                                Cell::Empty => {}
-                                Cell::Roll => {
-                                    adjacent_rolls += 1;
-                                }
-                            }
-                        }
-                    }
+    let grid = common::parse_or_exit(&day, &input);
+    let removal = remove_rolls(&grid, &day.rule);
+    let frames = render::frames(&grid, &removal);
 
-                    if adjacent_rolls < 4 {
-                        stop = false;
-                        grid[row_index][col_index] = Cell::Empty;
-                        result += 1;
-                    }
-                }
+    if options.animate {
+        for (round, frame) in frames.iter().enumerate() {
+            // Clear the screen and draw from the top left.
+            print!("\x1b[2J\x1b[H{}round {round}", render::ansi(frame));
+            if round > 0 {
+                print!(": removed {}", removal.rounds[round - 1].len());
             }
+            println!();
+            thread::sleep(options.delay);
         }
+    }
 
-        if stop {
-            break;
+    if let Some(dir) = &options.frames {
+        let result = fs::create_dir_all(dir).and_then(|()| {
+            frames.iter().enumerate().try_for_each(|(round, frame)| {
+                let path = dir.join(format!("round-{round:03}.ppm"));
+                let mut out = BufWriter::new(File::create(path)?);
+                render::write_ppm(&mut out, frame, options.scale)
+            })
+        });
+
+        if let Err(error) = result {
+            eprintln!(
+                "error: couldn't write frames to `{}`: {error}",
+                dir.display()
+            );
+            process::exit(1);
         }
     }
 
-    result
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn day_four_part_one() {
-        let input = "..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.";
-
-        assert_eq!(part_one(input), 13);
+    #[cfg(feature = "gif")]
+    if let Some(path) = &options.gif {
+        let delay = u16::try_from(options.delay.as_millis() / 10).unwrap_or(u16::MAX);
+        let result = File::create(path)
+            .map_err(gif::EncodingError::from)
+            .and_then(|file| {
+                render::write_gif(&mut BufWriter::new(file), &frames, options.scale, delay)
+            });
+
+        if let Err(error) = result {
+            eprintln!("error: couldn't write `{}`: {error}", path.display());
+            process::exit(1);
+        }
     }
 
-    #[test]
-    fn day_four_part_two() {
-        let input = "..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.";
-
-        assert_eq!(part_two(input), 43);
-    }
+    println!(
+        "Removed {} rolls in {} rounds",
+        removal.removed(),
+        removal.rounds.len()
+    );
 }
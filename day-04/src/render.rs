@@ -0,0 +1,182 @@
+//! Pictures of the removal, one frame per round, for watching the rolls get peeled away.
+
+use std::io::{self, Write};
+
+use grid::Grid;
+
+use crate::{Cell, Removal};
+
+/// What a cell looks like in a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shade {
+    Empty,
+    Roll,
+    /// A roll removed in the frame's round.
+    Removed,
+}
+
+impl Shade {
+    fn rgb(self) -> [u8; 3] {
+        match self {
+            Shade::Empty => [32, 32, 32],
+            Shade::Roll => [222, 184, 96],
+            Shade::Removed => [220, 48, 48],
+        }
+    }
+
+    /// The shade's slot in the GIF palette.
+    #[cfg(feature = "gif")]
+    fn index(self) -> u8 {
+        match self {
+            Shade::Empty => 0,
+            Shade::Roll => 1,
+            Shade::Removed => 2,
+        }
+    }
+}
+
+/// The starting grid, then the grid after each round with that round's rolls marked as removed.
+pub fn frames(grid: &Grid<Cell>, removal: &Removal) -> Vec<Grid<Shade>> {
+    let mut frame = grid.map(|cell| match cell {
+        Cell::Empty => Shade::Empty,
+        Cell::Roll => Shade::Roll,
+    });
+    let mut frames = vec![frame.clone()];
+
+    for round in &removal.rounds {
+        frame = frame.map(|&shade| match shade {
+            Shade::Removed => Shade::Empty,
+            shade => shade,
+        });
+
+        for &position in round {
+            frame[position] = Shade::Removed;
+        }
+
+        frames.push(frame.clone());
+    }
+
+    frames
+}
+
+/// The frame as coloured text for a terminal.
+pub fn ansi(frame: &Grid<Shade>) -> String {
+    let mut text = String::new();
+
+    for row in frame.rows() {
+        for shade in row {
+            text.push_str(match shade {
+                Shade::Empty => "\x1b[2m.",
+                Shade::Roll => "\x1b[33m@",
+                Shade::Removed => "\x1b[1;31m@",
+            });
+            text.push_str("\x1b[0m");
+        }
+        text.push('\n');
+    }
+
+    text
+}
+
+/// Writes the frame as a binary PPM image, drawing each cell as a `scale` pixel square.
+pub fn write_ppm(out: &mut impl Write, frame: &Grid<Shade>, scale: usize) -> io::Result<()> {
+    writeln!(
+        out,
+        "P6\n{} {}\n255",
+        frame.width() * scale,
+        frame.height() * scale
+    )?;
+
+    for row in frame.rows() {
+        let line: Vec<u8> = row
+            .iter()
+            .flat_map(|shade| shade.rgb().repeat(scale))
+            .collect();
+
+        for _ in 0..scale {
+            out.write_all(&line)?;
+        }
+    }
+
+    out.flush()
+}
+
+/// Writes the frames as an endlessly looping animated GIF, showing each frame for `delay`
+/// hundredths of a second and drawing each cell as a `scale` pixel square.
+#[cfg(feature = "gif")]
+pub fn write_gif(
+    out: &mut impl Write,
+    frames: &[Grid<Shade>],
+    scale: usize,
+    delay: u16,
+) -> Result<(), gif::EncodingError> {
+    let Some(first) = frames.first() else {
+        return Ok(());
+    };
+
+    let too_big = || {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "the image is too big for a GIF",
+        )
+    };
+    let width = u16::try_from(first.width() * scale).map_err(|_| too_big())?;
+    let height = u16::try_from(first.height() * scale).map_err(|_| too_big())?;
+
+    // Four slots rather than three, as a GIF palette's size must be a power of two.
+    let mut palette = [0u8; 4 * 3];
+    for shade in [Shade::Empty, Shade::Roll, Shade::Removed] {
+        let slot = usize::from(shade.index()) * 3;
+        palette[slot..slot + 3].copy_from_slice(&shade.rgb());
+    }
+
+    let mut encoder = gif::Encoder::new(out, width, height, &palette)?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    for frame in frames {
+        let mut pixels = Vec::with_capacity(usize::from(width) * usize::from(height));
+
+        for row in frame.rows() {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|&shade| [shade.index()].repeat(scale))
+                .collect();
+
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+
+        let mut frame = gif::Frame::from_indexed_pixels(width, height, pixels, None);
+        frame.delay = delay;
+        encoder.write_frame(&frame)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+    use crate::{Day04, Rule, remove_rolls};
+
+    #[test]
+    fn frames_mark_each_round() {
+        let grid = Day04::default().parse("@@@\n@@@\n@@@").unwrap();
+        let removal = remove_rolls(&grid, &Rule::default());
+
+        let frames = frames(&grid, &removal);
+
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[1][grid::Position::new(0, 0)], Shade::Removed);
+        assert_eq!(frames[2][grid::Position::new(0, 0)], Shade::Empty);
+        assert_eq!(frames[2][grid::Position::new(0, 1)], Shade::Removed);
+
+        let mut ppm = Vec::new();
+        write_ppm(&mut ppm, &frames[0], 2).unwrap();
+        assert!(ppm.starts_with(b"P6\n6 6\n255\n"));
+        assert_eq!(ppm.len(), "P6\n6 6\n255\n".len() + 6 * 6 * 3);
+    }
+}
@@ -0,0 +1,170 @@
+use std::{fmt::Display, ops::RangeInclusive};
+
+use common::{IntervalSet, ParseError, Solution, Source};
+
+#[derive(Debug, Default)]
+pub struct Day05;
+
+/// How many ingredient IDs are fresh, or `None` when there are more than a `usize` counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FreshIds(pub Option<usize>);
+
+impl Display for FreshIds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(count) => write!(f, "{count}"),
+            None => write!(f, "error: the number of fresh IDs doesn't fit in a `usize`"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Inventory {
+    /// The fresh ingredient ID ranges, merged.
+    ranges: IntervalSet<usize>,
+    ingredients: Vec<usize>,
+}
+
+impl Inventory {
+    /// Each fresh ingredient, in input order, with the merged range it's in. Every lookup is a
+    /// binary search over the merged ranges.
+    pub fn fresh(&self) -> impl Iterator<Item = (usize, RangeInclusive<usize>)> + '_ {
+        self.ingredients
+            .iter()
+            .filter_map(|&id| Some((id, self.ranges.interval(id)?)))
+    }
+
+    /// Every merged range, in order, with how many of the ingredients are in it.
+    pub fn histogram(&self) -> Vec<(RangeInclusive<usize>, usize)> {
+        let mut histogram: Vec<_> = self.ranges.iter().map(|range| (range, 0)).collect();
+
+        for (_id, range) in self.fresh() {
+            let index = histogram
+                .binary_search_by_key(range.start(), |(range, _hits)| *range.start())
+                .expect("the range should be one of the merged ranges");
+            histogram[index].1 += 1;
+        }
+
+        histogram
+    }
+}
+
+impl Solution for Day05 {
+    type Input<'src> = Inventory;
+    type PartOne = usize;
+    type PartTwo = FreshIds;
+
+    fn parse<'src>(&self, input: &'src str) -> Result<Self::Input<'src>, ParseError> {
+        let source = Source::new(input);
+        let (ranges, ingredients) = input.split_once("\n\n").ok_or_else(|| {
+            source.missing_after(input.trim_end(), "a blank line before the ingredient IDs")
+        })?;
+
+        let ranges = ranges
+            .lines()
+            .map(|range| {
+                let (start, end) = source.split_once(range, '-')?;
+                let start: usize = source.number(start)?;
+                let end: usize = source.number(end)?;
+
+                Ok(start..=end)
+            })
+            .collect::<Result<_, _>>()?;
+
+        let ingredients = ingredients
+            .lines()
+            .map(|id| source.number(id))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Inventory {
+            ranges,
+            ingredients,
+        })
+    }
+
+    fn part_one(&self, inventory: &Self::Input<'_>) -> usize {
+        inventory.fresh().count()
+    }
+
+    fn part_two(&self, inventory: &Self::Input<'_>) -> FreshIds {
+        FreshIds(inventory.ranges.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_five_part_one() {
+        let input = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+        assert_eq!(Day05.part_one(&Day05.parse(input).unwrap()), 3);
+    }
+
+    #[test]
+    fn day_five_part_two() {
+        let input = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+        assert_eq!(
+            Day05.part_two(&Day05.parse(input).unwrap()),
+            FreshIds(Some(14))
+        );
+    }
+
+    #[test]
+    fn day_five_histogram() {
+        let input = "3-5
+10-14
+16-20
+12-18
+25-30
+
+1
+5
+8
+11
+17
+32";
+        let inventory = Day05.parse(input).unwrap();
+
+        assert_eq!(
+            inventory.fresh().collect::<Vec<_>>(),
+            [(5, 3..=5), (11, 10..=20), (17, 10..=20)]
+        );
+        assert_eq!(
+            inventory.histogram(),
+            [(3..=5, 1), (10..=20, 2), (25..=30, 0)]
+        );
+    }
+
+    #[test]
+    fn day_five_every_id_fresh() {
+        let input = format!("0-{}\n\n1", usize::MAX);
+
+        assert_eq!(
+            Day05.part_two(&Day05.parse(&input).unwrap()).to_string(),
+            "error: the number of fresh IDs doesn't fit in a `usize`"
+        );
+    }
+}
@@ -0,0 +1,483 @@
+use std::{fmt::Display, ops::Range};
+
+use common::{ParseError, Solution, Source};
+
+mod operation;
+mod value;
+
+pub use operation::{ArithmeticError, Operation};
+pub use value::Value;
+
+#[derive(Debug, Default)]
+pub struct Day06;
+
+/// Every problem's answer, in worksheet order, and their grand total.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Totals {
+    pub problems: Vec<Value>,
+    pub total: Value,
+}
+
+impl FromIterator<Value> for Totals {
+    fn from_iter<I: IntoIterator<Item = Value>>(iter: I) -> Self {
+        let problems: Vec<Value> = iter.into_iter().collect();
+        let total = problems
+            .iter()
+            .fold(Value::ZERO, |sum, value| sum.add(value));
+
+        Self { problems, total }
+    }
+}
+
+impl Display for Totals {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.total)
+    }
+}
+
+/// A problem that has no answer, numbered from 1 in worksheet order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProblemError {
+    pub problem: usize,
+    pub error: ArithmeticError,
+}
+
+impl Display for ProblemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "problem {}: {}", self.problem, self.error)
+    }
+}
+
+impl std::error::Error for ProblemError {}
+
+/// The worksheet's totals, or the first problem that stopped them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer(pub Result<Totals, ProblemError>);
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Ok(totals) => write!(f, "{totals}"),
+            Err(error) => write!(f, "error: {error}"),
+        }
+    }
+}
+
+/// A problem written out as an expression, like `123 * 45 * 6 = 33210`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub operation: Operation,
+    pub operands: Vec<Value>,
+    pub result: Result<Value, ArithmeticError>,
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let (Err(error @ ArithmeticError::NotANumber(_)), []) =
+            (&self.result, self.operands.as_slice())
+        {
+            return write!(f, "error: {error}");
+        }
+
+        let operands: Vec<String> = self.operands.iter().map(Value::to_string).collect();
+
+        match self.operation {
+            Operation::Min | Operation::Max => {
+                write!(f, "{}({})", self.operation.symbol(), operands.join(", "))?;
+            }
+            _ => {
+                let separator = format!(" {} ", self.operation.symbol());
+                write!(f, "{}", operands.join(&separator))?;
+            }
+        }
+
+        match &self.result {
+            Ok(value) => write!(f, " = {value}"),
+            Err(error) => write!(f, ": error: {error}"),
+        }
+    }
+}
+
+/// How the digits of a problem make up its operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reading {
+    /// Every row is a number, the way people read the worksheet.
+    Rows,
+    /// Every column is a number with its most significant digit at the top, and the columns
+    /// are read right to left, the way cephalopods read it.
+    Columns,
+}
+
+/// A problem of the worksheet: a block of columns with a blank column on either side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem<'src> {
+    /// The problem's slice of every operand row, alignment spaces included. Ragged rows can
+    /// make a slice shorter than the span, or empty.
+    pub operands: Vec<&'src str>,
+    pub operation: Operation,
+    /// The columns the problem covers, counted in characters.
+    pub column_span: Range<usize>,
+}
+
+impl Problem<'_> {
+    /// The problem's operands in reading order. A row can hold digits that are only meant to
+    /// be read by columns, like `1 2`, which is an error when reading by rows.
+    pub fn values(&self, reading: Reading) -> Result<Vec<Value>, ArithmeticError> {
+        match reading {
+            Reading::Rows => self
+                .operands
+                .iter()
+                .map(|row| row.trim())
+                .filter(|digits| !digits.is_empty())
+                .map(|digits| {
+                    Value::parse(digits)
+                        .ok_or_else(|| ArithmeticError::NotANumber(digits.to_string()))
+                })
+                .collect(),
+            Reading::Columns => (0..self.column_span.len())
+                .rev()
+                .filter_map(|col| {
+                    // Operand rows are checked to be ASCII, so bytes and columns line up.
+                    let digits: String = self
+                        .operands
+                        .iter()
+                        .filter_map(|row| row.as_bytes().get(col))
+                        .filter(|&&byte| byte != b' ')
+                        .map(|&byte| char::from(byte))
+                        .collect();
+
+                    Value::parse(&digits)
+                })
+                .map(Ok)
+                .collect(),
+        }
+    }
+
+    pub fn solve(&self, reading: Reading) -> Result<Value, ArithmeticError> {
+        self.operation.apply(&self.values(reading)?)
+    }
+
+    pub fn explain(&self, reading: Reading) -> Explanation {
+        match self.values(reading) {
+            Ok(operands) => Explanation {
+                operation: self.operation,
+                result: self.operation.apply(&operands),
+                operands,
+            },
+            Err(error) => Explanation {
+                operation: self.operation,
+                operands: Vec::new(),
+                result: Err(error),
+            },
+        }
+    }
+}
+
+/// Makes sure every character of the line is either a space or `valid`.
+fn check_chars(
+    source: &Source,
+    line: &str,
+    expected: &str,
+    valid: impl Fn(char) -> bool,
+) -> Result<(), ParseError> {
+    match line
+        .char_indices()
+        .find(|&(_i, ch)| ch != ' ' && !valid(ch))
+    {
+        Some((i, ch)) => Err(source.unexpected(&line[i..i + ch.len_utf8()], expected)),
+        None => Ok(()),
+    }
+}
+
+/// The part of `line` in the character columns `span`, cut short where the line ends.
+fn columns(line: &str, span: Range<usize>) -> &str {
+    let offset = |col| {
+        line.char_indices()
+            .nth(col)
+            .map_or(line.len(), |(i, _ch)| i)
+    };
+
+    &line[offset(span.start)..offset(span.end)]
+}
+
+/// Splits the worksheet into the runs of columns that aren't blank on every line. Lines
+/// shorter than the widest one count as blank past their end.
+fn problem_spans(lines: &[&str]) -> Vec<Range<usize>> {
+    let width = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut blank = vec![true; width];
+    for line in lines {
+        for (col, ch) in line.chars().enumerate() {
+            if ch != ' ' {
+                blank[col] = false;
+            }
+        }
+    }
+
+    let mut spans = Vec::new();
+    let mut start = None;
+
+    for (col, &blank) in blank.iter().chain([&true]).enumerate() {
+        match (start, blank) {
+            (None, false) => start = Some(col),
+            (Some(first), true) => {
+                spans.push(first..col);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    spans
+}
+
+fn parse_operation(source: &Source, symbols: &str) -> Result<Operation, ParseError> {
+    let mut tokens = symbols.split(' ').filter(|token| !token.is_empty());
+
+    let Some(token) = tokens.next() else {
+        return Err(source.unexpected(&symbols[..0], "an operation under the problem"));
+    };
+    let operation = Operation::from_symbol(token)
+        .ok_or_else(|| source.unexpected(token, "`+`, `-`, `*`, `/`, `min` or `max`"))?;
+    if let Some(token) = tokens.next() {
+        return Err(source.unexpected(token, "one operation per problem"));
+    }
+
+    Ok(operation)
+}
+
+/// Works out every problem, stopping at the first one without an answer.
+fn solve_all(problems: &[Problem], reading: Reading) -> Answer {
+    Answer(
+        problems
+            .iter()
+            .enumerate()
+            .map(|(i, problem)| {
+                problem.solve(reading).map_err(|error| ProblemError {
+                    problem: i + 1,
+                    error,
+                })
+            })
+            .collect(),
+    )
+}
+
+impl Solution for Day06 {
+    type Input<'src> = Vec<Problem<'src>>;
+    type PartOne = Answer;
+    type PartTwo = Answer;
+
+    fn parse<'src>(&self, input: &'src str) -> Result<Self::Input<'src>, ParseError> {
+        let source = Source::new(input);
+        let lines: Vec<&str> = input.lines().collect();
+
+        let Some((operations, operands)) = lines.split_last() else {
+            return Err(source.unexpected(input, "a worksheet"));
+        };
+
+        for line in operands {
+            check_chars(&source, line, "a digit", |ch| ch.is_ascii_digit())?;
+        }
+
+        problem_spans(&lines)
+            .into_iter()
+            .map(|column_span| {
+                Ok(Problem {
+                    operands: operands
+                        .iter()
+                        .map(|line| columns(line, column_span.clone()))
+                        .collect(),
+                    operation: parse_operation(&source, columns(operations, column_span.clone()))?,
+                    column_span,
+                })
+            })
+            .collect()
+    }
+
+    fn part_one(&self, problems: &Self::Input<'_>) -> Answer {
+        solve_all(problems, Reading::Rows)
+    }
+
+    fn part_two(&self, problems: &Self::Input<'_>) -> Answer {
+        solve_all(problems, Reading::Columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_six_part_one() {
+        let input = "123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  ";
+
+        let totals = Day06.part_one(&Day06.parse(input).unwrap()).0.unwrap();
+
+        assert_eq!(
+            totals.problems,
+            [33210, 490, 4243455, 401].map(Value::Small)
+        );
+        assert_eq!(totals.total, Value::Small(4277556));
+    }
+
+    #[test]
+    fn day_six_part_two() {
+        let input = "123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  ";
+
+        let totals = Day06.part_two(&Day06.parse(input).unwrap()).0.unwrap();
+
+        assert_eq!(
+            totals.problems,
+            [8544, 625, 3253600, 1058].map(Value::Small)
+        );
+        assert_eq!(totals.total, Value::Small(3263827));
+    }
+
+    #[test]
+    fn day_six_tall_multiplication() {
+        let operands = "99999\n".repeat(12);
+        let input = format!("{operands}*    ");
+
+        let totals = Day06.part_one(&Day06.parse(&input).unwrap()).0.unwrap();
+
+        let expected = (0..12).fold(num_bigint::BigInt::from(1), |product, _| product * 99999);
+        assert_eq!(totals.total, Value::Big(expected));
+    }
+
+    #[test]
+    fn day_six_problems() {
+        let input = "123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  ";
+
+        let problems = Day06.parse(input).unwrap();
+
+        assert_eq!(
+            problems[2],
+            Problem {
+                operands: vec![" 51", "387", "215"],
+                operation: Operation::Multiply,
+                column_span: 8..11,
+            }
+        );
+        assert_eq!(
+            problems[3].values(Reading::Rows).unwrap(),
+            [64, 23, 314].map(Value::Small)
+        );
+        assert_eq!(
+            problems[3].values(Reading::Columns).unwrap(),
+            [4, 431, 623].map(Value::Small)
+        );
+    }
+
+    #[test]
+    fn day_six_ragged_lines() {
+        let input = "123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   +";
+
+        let problems = Day06.parse(input).unwrap();
+
+        assert_eq!(problems[3].operands, ["64", "23", "314"]);
+        assert_eq!(
+            Day06.part_one(&problems).0.unwrap().total,
+            Value::Small(4277556)
+        );
+        assert_eq!(
+            Day06.part_two(&problems).0.unwrap().total,
+            Value::Small(3263827)
+        );
+    }
+
+    #[test]
+    fn day_six_parse_errors() {
+        let error = Day06.parse("12 3×4\n*  +").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: expected a digit, found `×`"
+        );
+
+        let error = Day06.parse("12 34\n*").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected an operation under the problem, found end of input"
+        );
+
+        let error = Day06.parse("1234\n* +").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected one operation per problem, found `+`"
+        );
+    }
+
+    #[test]
+    fn day_six_explain() {
+        let input = "123 328  51  64
+ 45 64  387  23
+  6 98  215 314
+*   -   max  / ";
+
+        let problems = Day06.parse(input).unwrap();
+        let explain = |reading| -> Vec<String> {
+            problems
+                .iter()
+                .map(|problem| problem.explain(reading).to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            explain(Reading::Rows),
+            [
+                "123 * 45 * 6 = 33210",
+                "328 - 64 - 98 = 166",
+                "max(51, 387, 215) = 387",
+                "64 / 23 / 314: error: 64 / 23 leaves a remainder of 18",
+            ]
+        );
+        assert_eq!(
+            Day06.part_one(&problems).to_string(),
+            "error: problem 4: 64 / 23 leaves a remainder of 18"
+        );
+        assert_eq!(explain(Reading::Columns)[1], "8 - 248 - 369 = -609");
+    }
+
+    #[test]
+    fn day_six_unknown_operation() {
+        let error = Day06.parse("12 34\n+  ×\n").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected `+`, `-`, `*`, `/`, `min` or `max`, found `×`"
+        );
+    }
+
+    #[test]
+    fn day_six_row_that_isnt_a_number() {
+        let problems = Day06.parse("1 2\n345\n+  \n").unwrap();
+
+        assert_eq!(
+            Day06.part_one(&problems).to_string(),
+            "error: problem 1: `1 2` isn't a single number"
+        );
+        assert_eq!(
+            problems[0].explain(Reading::Rows).to_string(),
+            "error: `1 2` isn't a single number"
+        );
+        assert_eq!(
+            problems[0].explain(Reading::Columns).to_string(),
+            "25 + 4 + 13 = 42"
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use std::fmt::Display;
+
+use crate::Value;
+
+/// What a problem does with its operands, worked left to right in reading order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Subtract,
+    Multiply,
+    /// Exact division: a remainder is an error rather than being rounded away.
+    Divide,
+    Min,
+    Max,
+}
+
+impl Operation {
+    /// Reads the symbol written under a problem.
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "+" => Some(Operation::Add),
+            "-" => Some(Operation::Subtract),
+            "*" => Some(Operation::Multiply),
+            "/" => Some(Operation::Divide),
+            "min" => Some(Operation::Min),
+            "max" => Some(Operation::Max),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Operation::Add => "+",
+            Operation::Subtract => "-",
+            Operation::Multiply => "*",
+            Operation::Divide => "/",
+            Operation::Min => "min",
+            Operation::Max => "max",
+        }
+    }
+
+    /// Adding and multiplying start from their identity, so they work on no operands at all.
+    /// The others start from the first operand and need at least one.
+    pub fn apply(self, operands: &[Value]) -> Result<Value, ArithmeticError> {
+        let (start, rest) = match self {
+            Operation::Add => (Value::ZERO, operands),
+            Operation::Multiply => (Value::ONE, operands),
+            _ => {
+                let (first, rest) = operands
+                    .split_first()
+                    .ok_or(ArithmeticError::NoOperands(self))?;
+                (first.clone(), rest)
+            }
+        };
+
+        rest.iter()
+            .try_fold(start, |result, value| self.combine(result, value))
+    }
+
+    fn combine(self, left: Value, right: &Value) -> Result<Value, ArithmeticError> {
+        Ok(match self {
+            Operation::Add => left.add(right),
+            Operation::Subtract => left.sub(right),
+            Operation::Multiply => left.mul(right),
+            Operation::Divide => {
+                if *right == Value::ZERO {
+                    return Err(ArithmeticError::DivisionByZero { dividend: left });
+                }
+
+                let remainder = left.rem(right);
+                if remainder != Value::ZERO {
+                    return Err(ArithmeticError::Remainder {
+                        dividend: left,
+                        divisor: right.clone(),
+                        remainder,
+                    });
+                }
+
+                left.div(right)
+            }
+            Operation::Min => left.min(right.clone()),
+            Operation::Max => left.max(right.clone()),
+        })
+    }
+}
+
+/// Why a problem has no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArithmeticError {
+    /// An operation without an identity was given nothing to work on.
+    NoOperands(Operation),
+    /// An operand row with more than one number in it, which only reads by columns.
+    NotANumber(String),
+    DivisionByZero {
+        dividend: Value,
+    },
+    Remainder {
+        dividend: Value,
+        divisor: Value,
+        remainder: Value,
+    },
+}
+
+impl Display for ArithmeticError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArithmeticError::NoOperands(operation) => {
+                write!(f, "`{}` needs at least one operand", operation.symbol())
+            }
+            ArithmeticError::NotANumber(row) => write!(f, "`{row}` isn't a single number"),
+            ArithmeticError::DivisionByZero { dividend } => {
+                write!(f, "{dividend} can't be divided by zero")
+            }
+            ArithmeticError::Remainder {
+                dividend,
+                divisor,
+                remainder,
+            } => write!(
+                f,
+                "{dividend} / {divisor} leaves a remainder of {remainder}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ArithmeticError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(values: &[i64]) -> Vec<Value> {
+        values.iter().copied().map(Value::Small).collect()
+    }
+
+    #[test]
+    fn works_left_to_right() {
+        let operands = values(&[360, 6, 4]);
+
+        assert_eq!(Operation::Subtract.apply(&operands), Ok(Value::Small(350)));
+        assert_eq!(Operation::Divide.apply(&operands), Ok(Value::Small(15)));
+        assert_eq!(Operation::Min.apply(&operands), Ok(Value::Small(4)));
+        assert_eq!(Operation::Max.apply(&operands), Ok(Value::Small(360)));
+        assert_eq!(Operation::Add.apply(&[]), Ok(Value::ZERO));
+    }
+
+    #[test]
+    fn rejects_inexact_division() {
+        assert_eq!(
+            Operation::Divide.apply(&values(&[7, 2])),
+            Err(ArithmeticError::Remainder {
+                dividend: Value::Small(7),
+                divisor: Value::Small(2),
+                remainder: Value::ONE,
+            })
+        );
+        assert_eq!(
+            Operation::Divide.apply(&values(&[7, 0])),
+            Err(ArithmeticError::DivisionByZero {
+                dividend: Value::Small(7)
+            })
+        );
+        assert_eq!(
+            Operation::Max.apply(&[]),
+            Err(ArithmeticError::NoOperands(Operation::Max))
+        );
+    }
+}
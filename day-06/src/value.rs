@@ -0,0 +1,165 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use num_bigint::BigInt;
+
+/// A worksheet number, kept in an `i64` while it fits and moved to an `i128` and then to
+/// arbitrary precision when it doesn't, so nothing a worksheet does can overflow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Small(i64),
+    /// Only used for values that don't fit in an `i64`.
+    Wide(i128),
+    /// Only used for values that don't fit in an `i128`.
+    Big(BigInt),
+}
+
+impl Value {
+    pub const ZERO: Value = Value::Small(0);
+    pub const ONE: Value = Value::Small(1);
+
+    /// Reads a run of decimal digits.
+    pub fn parse(digits: &str) -> Option<Value> {
+        if let Ok(value) = digits.parse() {
+            return Some(Value::Small(value));
+        }
+        if let Ok(value) = digits.parse() {
+            return Some(Value::Wide(value));
+        }
+        BigInt::parse_bytes(digits.as_bytes(), 10).map(Value::Big)
+    }
+
+    pub fn add(&self, other: &Value) -> Value {
+        self.combine(other, i64::checked_add, i128::checked_add, |a, b| a + b)
+    }
+
+    pub fn sub(&self, other: &Value) -> Value {
+        self.combine(other, i64::checked_sub, i128::checked_sub, |a, b| a - b)
+    }
+
+    pub fn mul(&self, other: &Value) -> Value {
+        self.combine(other, i64::checked_mul, i128::checked_mul, |a, b| a * b)
+    }
+
+    /// Divides rounding towards zero. Panics if `other` is zero.
+    pub fn div(&self, other: &Value) -> Value {
+        self.combine(other, i64::checked_div, i128::checked_div, |a, b| a / b)
+    }
+
+    /// The remainder of dividing towards zero. Panics if `other` is zero.
+    pub fn rem(&self, other: &Value) -> Value {
+        self.combine(other, i64::checked_rem, i128::checked_rem, |a, b| a % b)
+    }
+
+    /// Applies the operation at the narrowest width that holds both operands and the result.
+    fn combine(
+        &self,
+        other: &Value,
+        small: fn(i64, i64) -> Option<i64>,
+        wide: fn(i128, i128) -> Option<i128>,
+        big: fn(BigInt, BigInt) -> BigInt,
+    ) -> Value {
+        if let (Value::Small(a), Value::Small(b)) = (self, other)
+            && let Some(result) = small(*a, *b)
+        {
+            return Value::Small(result);
+        }
+
+        if let (Some(a), Some(b)) = (self.as_i128(), other.as_i128())
+            && let Some(result) = wide(a, b)
+        {
+            return Value::from(result);
+        }
+
+        Value::from(big(self.to_big(), other.to_big()))
+    }
+
+    fn as_i128(&self) -> Option<i128> {
+        match self {
+            Value::Small(value) => Some(i128::from(*value)),
+            Value::Wide(value) => Some(*value),
+            Value::Big(_) => None,
+        }
+    }
+
+    fn to_big(&self) -> BigInt {
+        match self {
+            Value::Small(value) => BigInt::from(*value),
+            Value::Wide(value) => BigInt::from(*value),
+            Value::Big(value) => value.clone(),
+        }
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.as_i128(), other.as_i128()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => self.to_big().cmp(&other.to_big()),
+        }
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<i128> for Value {
+    fn from(value: i128) -> Self {
+        i64::try_from(value).map_or(Value::Wide(value), Value::Small)
+    }
+}
+
+impl From<BigInt> for Value {
+    fn from(value: BigInt) -> Self {
+        i128::try_from(&value).map_or(Value::Big(value), Value::from)
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Small(value) => write!(f, "{value}"),
+            Value::Wide(value) => write!(f, "{value}"),
+            Value::Big(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn promotes_on_overflow() {
+        let big = Value::Small(i64::MAX);
+
+        let wide = big.add(&Value::ONE);
+        assert_eq!(wide, Value::Wide(i128::from(i64::MAX) + 1));
+
+        let huge = wide.mul(&wide).mul(&Value::Small(4));
+        assert!(matches!(huge, Value::Big(_)));
+        assert_eq!(huge.to_string(), "340282366920938463463374607431768211456");
+
+        assert_eq!(
+            Value::parse("340282366920938463463374607431768211456"),
+            Some(huge)
+        );
+        assert_eq!(Value::parse("12"), Some(Value::Small(12)));
+    }
+
+    #[test]
+    fn orders_across_widths() {
+        let wide = Value::Small(i64::MIN).sub(&Value::ONE);
+        let big = wide.mul(&wide).mul(&wide);
+
+        assert!(matches!(wide, Value::Wide(_)));
+        assert!(big < wide && wide < Value::ZERO);
+        assert_eq!(big.div(&wide), wide.mul(&wide));
+        assert_eq!(
+            Value::Small(i64::MIN).div(&Value::Small(-1)),
+            Value::Wide(-i128::from(i64::MIN))
+        );
+    }
+}
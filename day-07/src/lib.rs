@@ -0,0 +1,700 @@
+use std::{collections::BTreeSet, fmt::Display};
+
+use common::{ParseError, Solution, Source};
+use grid::{Direction, Grid, Position};
+
+#[derive(Debug, Default)]
+pub struct Day07;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mirror {
+    /// `/`, which turns a beam going right upwards.
+    Slash,
+    /// `\`, which turns a beam going right downwards.
+    Backslash,
+}
+
+impl Mirror {
+    fn reflect(self, heading: Direction) -> Direction {
+        match (self, heading.is_vertical()) {
+            (Mirror::Slash, true) | (Mirror::Backslash, false) => heading.turn_right(),
+            (Mirror::Slash, false) | (Mirror::Backslash, true) => heading.turn_left(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    /// `.`, which beams pass straight through.
+    Empty,
+    /// `S`, where a beam starts heading down. Other beams pass straight through.
+    Start,
+    /// `^`, which stops a beam and starts one on either side of it, heading the same way.
+    Splitter,
+    Mirror(Mirror),
+    /// `#`, which stops a beam.
+    Absorber,
+}
+
+impl Cell {
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '.' => Some(Cell::Empty),
+            'S' => Some(Cell::Start),
+            '^' => Some(Cell::Splitter),
+            '/' => Some(Cell::Mirror(Mirror::Slash)),
+            '\\' => Some(Cell::Mirror(Mirror::Backslash)),
+            '#' => Some(Cell::Absorber),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ch = match self {
+            Cell::Empty => '.',
+            Cell::Start => 'S',
+            Cell::Splitter => '^',
+            Cell::Mirror(Mirror::Slash) => '/',
+            Cell::Mirror(Mirror::Backslash) => '\\',
+            Cell::Absorber => '#',
+        };
+
+        write!(f, "{ch}")
+    }
+}
+
+/// A beam going into a cell, which decides where it goes next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Beam {
+    pub position: Position,
+    pub heading: Direction,
+}
+
+impl Beam {
+    /// The beam's bit in a cell's set of headings.
+    fn bit(self) -> u8 {
+        1 << self.heading as u8
+    }
+}
+
+/// Why the timelines can't be counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimelineError {
+    /// A beam comes back round to where it has already been, so its timelines never end.
+    Loop(Beam),
+    Overflow,
+}
+
+impl Display for TimelineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimelineError::Loop(beam) => write!(
+                f,
+                "the beam loops forever through line {}, column {}",
+                beam.position.row + 1,
+                beam.position.col + 1
+            ),
+            TimelineError::Overflow => write!(f, "the number of timelines doesn't fit in a `u128`"),
+        }
+    }
+}
+
+impl std::error::Error for TimelineError {}
+
+/// The number of timelines, or why they can't be counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timelines(pub Result<u128, TimelineError>);
+
+impl Display for Timelines {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Ok(timelines) => write!(f, "{timelines}"),
+            Err(error) => write!(f, "error: {error}"),
+        }
+    }
+}
+
+/// Where a timeline's beam has got to while counting them.
+#[derive(Debug, Clone, Copy)]
+enum Progress {
+    Unseen,
+    /// Still counting the timelines after the beam, so meeting it again is a loop.
+    Open,
+    Done(u128),
+}
+
+/// The tachyon manifold: a grid of cells with beams starting at every `S`.
+#[derive(Debug, Clone)]
+pub struct Manifold {
+    cells: Grid<Cell>,
+    starts: Vec<Position>,
+}
+
+impl Manifold {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let source = Source::new(input);
+        let cells = Grid::parse(&source, input, |ch, token| {
+            Cell::from_char(ch)
+                .ok_or_else(|| source.unexpected(token, "`.`, `S`, `^`, `/`, `\\` or `#`"))
+        })?;
+
+        let starts: Vec<Position> = cells
+            .iter()
+            .filter(|&(_position, &cell)| cell == Cell::Start)
+            .map(|(position, _cell)| position)
+            .collect();
+        if starts.is_empty() {
+            return Err(source.missing_after(input.trim_end(), "a start `S`"));
+        }
+
+        Ok(Self { cells, starts })
+    }
+
+    fn start_beams(&self) -> impl Iterator<Item = Beam> + '_ {
+        self.starts.iter().map(|&position| Beam {
+            position,
+            heading: Direction::Down,
+        })
+    }
+
+    /// Sends the beam into its cell and returns the beams that come out, each going into the
+    /// next cell. A splitter's beams go into the cells either side of it, where they are
+    /// entered like any other. None come out once a beam is stopped or leaves the manifold.
+    fn enter(&self, beam: Beam) -> Vec<Beam> {
+        let onwards = |heading| {
+            self.cells
+                .step(beam.position, heading)
+                .map(|position| Beam { position, heading })
+        };
+
+        match self.cells[beam.position] {
+            Cell::Empty | Cell::Start => onwards(beam.heading).into_iter().collect(),
+            Cell::Mirror(mirror) => onwards(mirror.reflect(beam.heading)).into_iter().collect(),
+            Cell::Splitter => [beam.heading.turn_left(), beam.heading.turn_right()]
+                .into_iter()
+                .filter_map(|side| self.cells.step(beam.position, side))
+                .map(|position| Beam { position, ..beam })
+                .collect(),
+            Cell::Absorber => Vec::new(),
+        }
+    }
+
+    /// Follows the beams from every start, keeping the headings each cell has had a beam
+    /// going in as a bitmap, so merging beams are followed once and looping ones stop.
+    /// Returns the bitmap and the number of splitters the beams hit.
+    fn trace(&self) -> (Grid<u8>, u64) {
+        let mut visited = Grid::new(self.cells.width(), self.cells.height(), 0u8);
+        let mut hit = Grid::new(self.cells.width(), self.cells.height(), false);
+        let mut beams: Vec<Beam> = self.start_beams().collect();
+        let mut splits = 0;
+
+        for beam in &beams {
+            visited[beam.position] |= beam.bit();
+        }
+
+        while let Some(beam) = beams.pop() {
+            if self.cells[beam.position] == Cell::Splitter && !hit[beam.position] {
+                hit[beam.position] = true;
+                splits += 1;
+            }
+
+            for next in self.enter(beam) {
+                if visited[next.position] & next.bit() == 0 {
+                    visited[next.position] |= next.bit();
+                    beams.push(next);
+                }
+            }
+        }
+
+        (visited, splits)
+    }
+
+    /// How many splitters the beams hit.
+    pub fn count_splits(&self) -> u64 {
+        self.trace().1
+    }
+
+    /// Every cell a beam passes through, starts and mirrors included. Splitters and absorbers
+    /// stop the beams going into them, so they aren't.
+    pub fn beams(&self) -> BTreeSet<Position> {
+        let (visited, _splits) = self.trace();
+
+        visited
+            .iter()
+            .filter(|&(position, &headings)| {
+                headings != 0 && !matches!(self.cells[position], Cell::Splitter | Cell::Absorber)
+            })
+            .map(|(position, _headings)| position)
+            .collect()
+    }
+
+    /// How many timelines the beams end up in, counting every way through the manifold. A
+    /// beam that can go nowhere else ends one timeline. Counts double with every splitter,
+    /// hence `u128`.
+    pub fn count_timelines(&self) -> Result<u128, TimelineError> {
+        if self.only_falls() {
+            self.count_timelines_by_rows()
+        } else {
+            self.count_timelines_by_search()
+        }
+    }
+
+    /// Whether every beam only ever goes down: there are no mirrors or absorbers, and no
+    /// splitter sends a beam sideways into another splitter.
+    fn only_falls(&self) -> bool {
+        self.cells.iter().all(|(position, cell)| match cell {
+            Cell::Empty | Cell::Start => true,
+            Cell::Splitter => [Direction::Left, Direction::Right]
+                .into_iter()
+                .filter_map(|side| self.cells.step(position, side))
+                .all(|side| self.cells[side] != Cell::Splitter),
+            Cell::Mirror(_) | Cell::Absorber => false,
+        })
+    }
+
+    /// Walks down a row at a time with the number of timelines that have a beam going into
+    /// each column. A splitter hands its count to both sides, and timelines whose beam can't
+    /// go anywhere end where they are. Only works when beams [only fall](Self::only_falls).
+    fn count_timelines_by_rows(&self) -> Result<u128, TimelineError> {
+        let add = |a: u128, b: u128| a.checked_add(b).ok_or(TimelineError::Overflow);
+        let width = self.cells.width();
+        let mut timelines = vec![0u128; width];
+        let mut ended = 0;
+
+        for row in 0..self.cells.height() {
+            for start in self.starts.iter().filter(|start| start.row == row) {
+                timelines[start.col] = add(timelines[start.col], 1)?;
+            }
+
+            let mut next = vec![0u128; width];
+
+            for (col, &count) in timelines.iter().enumerate() {
+                if count == 0 {
+                    continue;
+                }
+
+                let position = Position::new(row, col);
+                let cols: Vec<usize> = match self.cells[position] {
+                    Cell::Splitter => [Direction::Left, Direction::Right]
+                        .into_iter()
+                        .filter_map(|side| self.cells.step(position, side))
+                        .map(|side| side.col)
+                        .collect(),
+                    _ => vec![col],
+                };
+
+                if cols.is_empty() {
+                    ended = add(ended, count)?;
+                }
+                for col in cols {
+                    next[col] = add(next[col], count)?;
+                }
+            }
+
+            timelines = next;
+        }
+
+        // What's left has gone out of the bottom.
+        timelines.into_iter().try_fold(ended, add)
+    }
+
+    /// A depth first search over every beam position and heading, for when beams can turn
+    /// back on themselves. Finds loops as beams that are reached again while still being
+    /// counted.
+    fn count_timelines_by_search(&self) -> Result<u128, TimelineError> {
+        let index = |beam: Beam| {
+            (beam.position.row * self.cells.width() + beam.position.col) * 4 + beam.heading as usize
+        };
+        let mut progress = vec![Progress::Unseen; self.cells.width() * self.cells.height() * 4];
+        let mut total = 0;
+
+        for start in self.start_beams() {
+            // Each beam is pushed once to expand it and again, below what it expands into,
+            // to add them up.
+            let mut stack = vec![(start, false)];
+
+            while let Some((beam, expanded)) = stack.pop() {
+                if expanded {
+                    let next = self.enter(beam);
+                    let timelines = if next.is_empty() {
+                        1
+                    } else {
+                        next.iter()
+                            .try_fold(0u128, |sum, &beam| match progress[index(beam)] {
+                                Progress::Done(timelines) => {
+                                    sum.checked_add(timelines).ok_or(TimelineError::Overflow)
+                                }
+                                _ => unreachable!("beams are counted before what leads to them"),
+                            })?
+                    };
+                    progress[index(beam)] = Progress::Done(timelines);
+                    continue;
+                }
+
+                match progress[index(beam)] {
+                    Progress::Unseen => {}
+                    Progress::Open => return Err(TimelineError::Loop(beam)),
+                    Progress::Done(_) => continue,
+                }
+
+                progress[index(beam)] = Progress::Open;
+                stack.push((beam, true));
+                stack.extend(self.enter(beam).into_iter().map(|beam| (beam, false)));
+            }
+
+            let Progress::Done(timelines) = progress[index(start)] else {
+                unreachable!("the start is counted once its stack is empty");
+            };
+            total = timelines
+                .checked_add(total)
+                .ok_or(TimelineError::Overflow)?;
+        }
+
+        Ok(total)
+    }
+}
+
+impl Display for Manifold {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+impl Solution for Day07 {
+    type Input<'src> = Manifold;
+    type PartOne = u64;
+    type PartTwo = Timelines;
+
+    fn parse<'src>(&self, input: &'src str) -> Result<Self::Input<'src>, ParseError> {
+        Manifold::parse(input)
+    }
+
+    fn part_one(&self, manifold: &Self::Input<'_>) -> u64 {
+        manifold.count_splits()
+    }
+
+    fn part_two(&self, manifold: &Self::Input<'_>) -> Timelines {
+        Timelines(manifold.count_timelines())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn day_seven_part_one() {
+        let input = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+        assert_eq!(Day07.part_one(&Day07.parse(input).unwrap()), 21);
+    }
+
+    #[test]
+    fn day_seven_part_two() {
+        let input = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+        assert_eq!(
+            Day07.part_two(&Day07.parse(input).unwrap()),
+            Timelines(Ok(40))
+        );
+    }
+
+    #[test]
+    fn day_seven_beams() {
+        let input = "..S..
+.....
+..^..
+.^...
+.....";
+
+        let manifold = Day07.parse(input).unwrap();
+
+        assert_eq!(manifold.count_splits(), 2);
+        assert_eq!(
+            manifold.beams().into_iter().collect::<Vec<_>>(),
+            [
+                (0, 2),
+                (1, 2),
+                (2, 1),
+                (2, 3),
+                (3, 0),
+                (3, 2),
+                (3, 3),
+                (4, 0),
+                (4, 2),
+                (4, 3),
+            ]
+            .map(|(row, col)| Position::new(row, col))
+        );
+        // Tracing doesn't touch the manifold, so it can be traced again.
+        assert_eq!(manifold.count_splits(), 2);
+        assert_eq!(manifold.count_timelines(), Ok(3));
+    }
+
+    /// Follows every timeline recursively, caching how many extra timelines each beam
+    /// splits into.
+    fn count_timelines_recursive(manifold: &Manifold) -> u64 {
+        1 + timelines_from(manifold, manifold.starts[0], &mut HashMap::new())
+    }
+
+    fn timelines_from(
+        manifold: &Manifold,
+        start: Position,
+        cache: &mut HashMap<Position, u64>,
+    ) -> u64 {
+        if let Some(&result) = cache.get(&start) {
+            return result;
+        }
+
+        let mut beams = vec![start];
+        let mut timelines = 0;
+
+        while let Some(beam) = beams.pop() {
+            let Some(next) = manifold.cells.step(beam, Direction::Down) else {
+                continue;
+            };
+
+            match manifold.cells[next] {
+                Cell::Empty => {
+                    beams.push(next);
+                }
+                Cell::Splitter => {
+                    let split_beams: Vec<Position> = [Direction::Left, Direction::Right]
+                        .into_iter()
+                        .filter_map(|direction| manifold.cells.step(next, direction))
+                        .collect();
+
+                    match split_beams.as_slice() {
+                        [] => {}
+                        [split] => {
+                            timelines += timelines_from(manifold, *split, cache);
+                        }
+                        [left_split, right_split] => {
+                            timelines += 1;
+                            timelines += timelines_from(manifold, *right_split, cache);
+                            timelines += timelines_from(manifold, *left_split, cache);
+                        }
+                        _ => unreachable!(),
+                    }
+                }
+                _ => unreachable!(),
+            };
+        }
+
+        cache.insert(start, timelines);
+        timelines
+    }
+
+    #[test]
+    fn day_seven_timelines_match_recursion() {
+        let mut rng = common::XorShift::default();
+        let mut next = || rng.next_u32();
+
+        for _ in 0..100 {
+            let width = 1 + next() as usize % 12;
+            let start = next() as usize % width;
+
+            // The recursion doesn't look at the cells beside a splitter, so splitters are never
+            // put next to each other.
+            let mut input = String::new();
+            for row in 0..1 + next() % 16 {
+                let mut previous = '.';
+                for col in 0..width {
+                    previous = match (row, next() % 4) {
+                        (0, _) if col == start => 'S',
+                        (1.., 0) if previous != '^' => '^',
+                        _ => '.',
+                    };
+                    input.push(previous);
+                }
+                input.push('\n');
+            }
+
+            let manifold = Day07.parse(&input).unwrap();
+            assert_eq!(
+                manifold.count_timelines(),
+                Ok(u128::from(count_timelines_recursive(&manifold))),
+                "\n{input}"
+            );
+        }
+    }
+
+    /// A triangle of `rows` rows of splitters under the start, every one of them hit, so the
+    /// timelines double with each row.
+    fn splitter_triangle(rows: usize) -> String {
+        let mut input = format!("{}S{}\n", ".".repeat(rows), ".".repeat(rows));
+        for row in 0..rows {
+            let line: String = (0..2 * rows + 1)
+                .map(|col| {
+                    if (col + rows + row).is_multiple_of(2) {
+                        '^'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            input.push_str(&line);
+            input.push('\n');
+        }
+
+        input
+    }
+
+    #[test]
+    fn day_seven_deep_timelines() {
+        let manifold = Day07.parse(&splitter_triangle(127)).unwrap();
+        assert_eq!(manifold.count_timelines(), Ok(1 << 127));
+
+        let manifold = Day07.parse(&splitter_triangle(128)).unwrap();
+        assert_eq!(
+            Day07.part_two(&manifold).to_string(),
+            "error: the number of timelines doesn't fit in a `u128`"
+        );
+    }
+
+    #[test]
+    fn day_seven_cells_beside_splitters() {
+        let manifold = Day07.parse(".S.\n.^#\n...").unwrap();
+        assert_eq!(manifold.count_timelines(), Ok(2));
+        assert!(!manifold.beams().contains(&Position::new(2, 2)));
+
+        // The right half of the split is turned off the right side by `\`.
+        let manifold = Day07.parse(".S..\n.^\\.\n....").unwrap();
+        assert_eq!(manifold.count_timelines(), Ok(2));
+        assert!(manifold.beams().contains(&Position::new(1, 3)));
+        assert!(!manifold.beams().contains(&Position::new(2, 2)));
+
+        // Here `/` turns it back into the splitter from the side, which splits it up and down.
+        let manifold = Day07.parse(".S..\n.^/.\n....").unwrap();
+        assert_eq!(manifold.count_timelines(), Ok(3));
+        assert_eq!(manifold.count_splits(), 1);
+        assert!(!manifold.beams().contains(&Position::new(2, 2)));
+    }
+
+    #[test]
+    fn day_seven_mirrors_and_absorbers() {
+        let input = r"S..S.
+\.#..
+...^.
+../..";
+
+        let manifold = Day07.parse(input).unwrap();
+
+        // The left beam is turned right by `\` and stopped by `#`. The right one is split, and
+        // the left half of it is turned off the left side by `/`.
+        assert_eq!(manifold.count_splits(), 1);
+        assert_eq!(manifold.count_timelines(), Ok(3));
+        assert_eq!(
+            manifold.beams().into_iter().collect::<Vec<_>>(),
+            [
+                (0, 0),
+                (0, 3),
+                (1, 0),
+                (1, 1),
+                (1, 3),
+                (2, 2),
+                (2, 4),
+                (3, 0),
+                (3, 1),
+                (3, 2),
+                (3, 4),
+            ]
+            .map(|(row, col)| Position::new(row, col))
+        );
+    }
+
+    #[test]
+    fn day_seven_loop() {
+        let input = r"/.\
+..S
+\./";
+
+        let manifold = Day07.parse(input).unwrap();
+
+        assert_eq!(manifold.count_splits(), 0);
+        assert_eq!(manifold.beams().len(), 8);
+        assert_eq!(
+            Day07.part_two(&manifold).to_string(),
+            "error: the beam loops forever through line 2, column 3"
+        );
+    }
+
+    #[test]
+    fn day_seven_parse_errors() {
+        let error = Day07.parse("..S\n.|.\n...").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r"line 2, column 2: expected `.`, `S`, `^`, `/`, `\` or `#`, found `|`"
+        );
+
+        let error = Day07.parse("...\n.^.\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected a start `S`, found end of input"
+        );
+    }
+
+    #[test]
+    fn day_seven_row_pass_matches_search() {
+        let input = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...S...........
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+        let manifold = Day07.parse(input).unwrap();
+
+        assert!(manifold.only_falls());
+        assert_eq!(
+            manifold.count_timelines_by_rows(),
+            manifold.count_timelines_by_search()
+        );
+
+        let manifold = Day07.parse(&splitter_triangle(40)).unwrap();
+        assert_eq!(manifold.count_timelines_by_rows(), Ok(1 << 40));
+        assert_eq!(manifold.count_timelines_by_search(), Ok(1 << 40));
+    }
+}
@@ -0,0 +1,271 @@
+use std::{cmp, fmt::Display};
+
+use common::{DisjointSet, ParseError, Solution, Source};
+
+#[derive(Debug)]
+pub struct Day08 {
+    /// How many of the closest pairs of junctions get connected in part one.
+    pub connections: usize,
+}
+
+impl Default for Day08 {
+    fn default() -> Self {
+        Self { connections: 1000 }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Junction {
+    index: usize,
+    position: Position,
+}
+
+impl Junction {
+    fn parse(source: &Source, index: usize, position: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            index,
+            position: Position::parse(source, position)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    x: u64,
+    y: u64,
+    z: u64,
+}
+
+impl Position {
+    fn parse(source: &Source, value: &str) -> Result<Self, ParseError> {
+        let (x, rest) = source.split_once(value, ',')?;
+        let (y, z) = source.split_once(rest, ',')?;
+
+        Ok(Self {
+            x: source.number(x)?,
+            y: source.number(y)?,
+            z: source.number(z)?,
+        })
+    }
+
+    fn distance_squared(&self, other: &Position) -> u64 {
+        self.x.abs_diff(other.x).pow(2)
+            + self.y.abs_diff(other.y).pow(2)
+            + self.z.abs_diff(other.z).pow(2)
+    }
+}
+
+#[derive(Debug)]
+struct Distance {
+    value: u64,
+
+    junction1: usize,
+    junction2: usize,
+}
+
+impl Distance {
+    fn new(junction1: &Junction, junction2: &Junction) -> Self {
+        Self {
+            value: junction1.position.distance_squared(&junction2.position),
+            junction1: junction1.index,
+            junction2: junction2.index,
+        }
+    }
+}
+
+impl PartialEq for Distance {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for Distance {}
+
+impl PartialOrd for Distance {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Distance {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+/// The X coordinates of the last pair of junctions to join everything into one circuit,
+/// multiplied together. There's no such pair when there's only one junction to begin with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LastConnection(pub Option<u64>);
+
+impl Display for LastConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(product) => write!(f, "{product}"),
+            None => write!(f, "error: the junctions are already one circuit"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Playground {
+    junctions: Vec<Junction>,
+    /// Every pair of junctions, closest first.
+    distances: Vec<Distance>,
+}
+
+impl Solution for Day08 {
+    type Input<'src> = Playground;
+    type PartOne = usize;
+    type PartTwo = LastConnection;
+
+    fn parse<'src>(&self, input: &'src str) -> Result<Self::Input<'src>, ParseError> {
+        let source = Source::new(input);
+        let junctions: Vec<_> = input
+            .lines()
+            .enumerate()
+            .map(|(index, position)| Junction::parse(&source, index, position))
+            .collect::<Result<_, _>>()?;
+
+        let mut distances =
+            junctions
+                .iter()
+                .enumerate()
+                .fold(Vec::new(), |mut distances, (i, junction)| {
+                    distances.extend(
+                        junctions[i + 1..]
+                            .iter()
+                            .map(|junction2| Distance::new(junction, junction2)),
+                    );
+                    distances
+                });
+
+        distances.sort_unstable();
+
+        Ok(Playground {
+            junctions,
+            distances,
+        })
+    }
+
+    fn part_one(&self, playground: &Self::Input<'_>) -> usize {
+        let mut circuits = DisjointSet::new(playground.junctions.len());
+
+        for distance in playground.distances.iter().take(self.connections) {
+            circuits.union(distance.junction1, distance.junction2);
+        }
+
+        let mut lengths: Vec<_> = circuits.component_sizes().collect();
+
+        lengths.sort_by(|a, b| b.cmp(a));
+
+        lengths.iter().take(3).product()
+    }
+
+    fn part_two(&self, playground: &Self::Input<'_>) -> LastConnection {
+        let junctions = &playground.junctions;
+        let mut circuits = DisjointSet::new(junctions.len());
+
+        if circuits.all_connected() {
+            return LastConnection(None);
+        }
+
+        // Every pair is in the list, so connecting them all always ends in one circuit.
+        let last = playground.distances.iter().find(|distance| {
+            circuits.union(distance.junction1, distance.junction2);
+            circuits.all_connected()
+        });
+
+        LastConnection(last.map(|distance| {
+            junctions[distance.junction1].position.x * junctions[distance.junction2].position.x
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_eight_part_one() {
+        let input = "162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+        let day = Day08 { connections: 10 };
+        assert_eq!(day.part_one(&day.parse(input).unwrap()), 40);
+    }
+
+    #[test]
+    fn day_eight_part_two() {
+        let input = "162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+        let day = Day08::default();
+        assert_eq!(
+            day.part_two(&day.parse(input).unwrap()),
+            LastConnection(Some(25272))
+        );
+    }
+
+    #[test]
+    fn day_eight_one_junction() {
+        let day = Day08::default();
+        let playground = day.parse("162,817,812").unwrap();
+
+        assert_eq!(day.part_one(&playground), 1);
+        assert_eq!(
+            day.part_two(&playground).to_string(),
+            "error: the junctions are already one circuit"
+        );
+        assert_eq!(
+            day.part_two(&day.parse("162,817,812\n57,618,57").unwrap()),
+            LastConnection(Some(162 * 57))
+        );
+    }
+
+    #[test]
+    fn day_eight_parse_error() {
+        let error = Day08::default()
+            .parse("162,817,812\n57,618\n906,360,560")
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: expected `,`, found end of line"
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use std::{cmp, collections::HashMap};
+
+use common::{ParseError, Solution, Source};
+use grid::Grid;
+use itertools::Itertools;
+
+#[derive(Debug, Default)]
+pub struct Day09;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Position {
+    left: usize,
+    top: usize,
+}
+
+impl Position {
+    fn parse(source: &Source, value: &str) -> Result<Self, ParseError> {
+        let (left, top) = source.split_once(value, ',')?;
+
+        Ok(Self {
+            left: source.number(left)?,
+            top: source.number(top)?,
+        })
+    }
+}
+
+fn area(pos1: &Position, pos2: &Position) -> usize {
+    (pos1.left.abs_diff(pos2.left) + 1) * (pos1.top.abs_diff(pos2.top) + 1)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Tile {
+    Boundary,
+    Inside,
+    Outside,
+}
+
+#[derive(Debug)]
+struct Floor {
+    tiles: Grid<Tile>,
+    compressed_xs: HashMap<usize, usize>,
+    compressed_ys: HashMap<usize, usize>,
+}
+
+impl Floor {
+    fn new(compressed_xs: HashMap<usize, usize>, compressed_ys: HashMap<usize, usize>) -> Self {
+        let width = compressed_xs.values().max().unwrap() + 1;
+        let height = compressed_ys.values().max().unwrap() + 1;
+
+        Self {
+            tiles: Grid::new(width, height, Tile::Inside),
+            compressed_xs,
+            compressed_ys,
+        }
+    }
+
+    fn add_tiles(&mut self, positions: &[Position]) {
+        let pairs: Vec<_> = positions
+            .iter()
+            .circular_tuple_windows()
+            .map(|(pos1, pos2)| (self.compressed(pos1), self.compressed(pos2)))
+            .collect();
+
+        for (pos1, pos2) in pairs {
+            assert!(pos1.col == pos2.col || pos1.row == pos2.row);
+
+            if pos1.col == pos2.col {
+                // vertical
+                let y_min = cmp::min(pos1.row, pos2.row);
+                let y_max = cmp::max(pos1.row, pos2.row);
+
+                for y in y_min..=y_max {
+                    self.tiles[grid::Position::new(y, pos1.col)] = Tile::Boundary;
+                }
+            } else {
+                // horizontal
+                let x_min = cmp::min(pos1.col, pos2.col);
+                let x_max = cmp::max(pos1.col, pos2.col);
+
+                for x in x_min..=x_max {
+                    self.tiles[grid::Position::new(pos1.row, x)] = Tile::Boundary;
+                }
+            }
+        }
+    }
+
+    fn compressed(&self, position: &Position) -> grid::Position {
+        grid::Position {
+            row: *self.compressed_ys.get(&position.top).unwrap(),
+            col: *self.compressed_xs.get(&position.left).unwrap(),
+        }
+    }
+
+    fn fill(&mut self) {
+        let mut tiles = vec![grid::Position::new(0, 0)];
+
+        while let Some(pos) = tiles.pop() {
+            self.tiles[pos] = Tile::Outside;
+
+            for neighbour in self.tiles.neighbours(pos) {
+                if matches!(self.tiles[neighbour], Tile::Inside) {
+                    tiles.push(neighbour);
+                }
+            }
+        }
+    }
+
+    fn max_area_within_polygon(&self, positions: &[Position]) -> usize {
+        positions
+            .iter()
+            .enumerate()
+            .flat_map(|(i, pos1)| positions.iter().skip(i + 1).map(|pos2| (*pos1, *pos2)))
+            .filter(|(pos1, pos2)| {
+                let pos1_compressed = self.compressed(pos1);
+                let pos2_compressed = self.compressed(pos2);
+
+                let x_min = cmp::min(pos1_compressed.col, pos2_compressed.col);
+                let x_max = cmp::max(pos1_compressed.col, pos2_compressed.col);
+
+                let y_min = cmp::min(pos1_compressed.row, pos2_compressed.row);
+                let y_max = cmp::max(pos1_compressed.row, pos2_compressed.row);
+
+                (x_min..=x_max)
+                    .flat_map(|x| (y_min..=y_max).map(move |y| (x, y)))
+                    .all(|(x, y)| {
+                        matches!(
+                            self.tiles[grid::Position::new(y, x)],
+                            Tile::Inside | Tile::Boundary
+                        )
+                    })
+            })
+            .map(|(pos1, pos2)| area(&pos1, &pos2))
+            .max()
+            .unwrap()
+    }
+}
+
+impl Solution for Day09 {
+    type Input<'src> = Vec<Position>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse<'src>(&self, input: &'src str) -> Result<Self::Input<'src>, ParseError> {
+        let source = Source::new(input);
+        input
+            .lines()
+            .map(|line| Position::parse(&source, line))
+            .collect()
+    }
+
+    fn part_one(&self, positions: &Self::Input<'_>) -> usize {
+        positions
+            .iter()
+            .enumerate()
+            .flat_map(|(i, pos)| positions.iter().skip(i + 1).map(|pos2| area(pos, pos2)))
+            .max()
+            .unwrap()
+    }
+
+    fn part_two(&self, positions: &Self::Input<'_>) -> usize {
+        let mut xs: Vec<usize> = positions.clone().iter().map(|pos| pos.left).collect();
+        let mut ys: Vec<usize> = positions.clone().iter().map(|pos| pos.top).collect();
+
+        xs.push(usize::MIN);
+        xs.push(usize::MAX);
+        xs.sort_unstable();
+        xs.dedup();
+        let compressed_xs = HashMap::from_iter(xs.iter().enumerate().map(|(i, x)| (*x, i)));
+
+        ys.push(usize::MIN);
+        ys.push(usize::MAX);
+        ys.sort_unstable();
+        ys.dedup();
+        let compressed_ys = HashMap::from_iter(ys.iter().enumerate().map(|(i, y)| (*y, i)));
+
+        let mut floor = Floor::new(compressed_xs, compressed_ys);
+        floor.add_tiles(positions);
+        floor.fill();
+        floor.max_area_within_polygon(positions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_nine_part_one() {
+        let input = "7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3";
+        assert_eq!(Day09.part_one(&Day09.parse(input).unwrap()), 50);
+    }
+
+    #[test]
+    fn day_nine_part_two() {
+        let input = "7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3";
+        assert_eq!(Day09.part_two(&Day09.parse(input).unwrap()), 24);
+    }
+}
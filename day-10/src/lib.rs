@@ -0,0 +1,562 @@
+use std::{fmt::Display, iter::Peekable, str::CharIndices};
+
+use common::{ParseError, Solution, Source};
+use itertools::Itertools;
+
+#[derive(Debug, Default)]
+pub struct Day10;
+
+#[derive(Debug)]
+struct MachineParser<'src> {
+    source: Source<'src>,
+    line: &'src str,
+    chars: Peekable<CharIndices<'src>>,
+}
+
+impl<'src> MachineParser<'src> {
+    fn new(source: Source<'src>, line: &'src str) -> Self {
+        Self {
+            source,
+            line,
+            chars: line.char_indices().peekable(),
+        }
+    }
+
+    fn parse(&mut self) -> Result<Machine, ParseError> {
+        let (lights, count) = self.parse_lights()?;
+        self.eat_whitespace();
+        let buttons = self.parse_buttons(count)?;
+        let joltages = self.parse_joltages(count)?;
+
+        Ok(Machine {
+            lights,
+            buttons,
+            joltages,
+        })
+    }
+
+    /// The lights as a bitmask, and how many there are. Each light is a bit, so there can be
+    /// at most `usize::BITS` of them.
+    fn parse_lights(&mut self) -> Result<(usize, usize), ParseError> {
+        self.expect('[')?;
+
+        let mut lights = 0;
+        let mut shift = 0usize;
+
+        loop {
+            match self.chars.next() {
+                Some((_, ']')) => break,
+                next if shift == usize::BITS as usize => {
+                    return Err(self.unexpected(
+                        next,
+                        format_args!("`]` after at most {} lights", usize::BITS),
+                    ));
+                }
+                Some((_, '.')) => {}
+                Some((_, '#')) => lights |= 1 << shift,
+                next => return Err(self.unexpected(next, "`.`, `#` or `]`")),
+            };
+
+            shift += 1;
+        }
+
+        Ok((lights, shift))
+    }
+
+    fn parse_buttons(&mut self, lights: usize) -> Result<Vec<usize>, ParseError> {
+        let mut buttons = Vec::new();
+
+        while let Some((_, '(')) = self.chars.peek() {
+            self.chars.next();
+
+            let mut button = 0;
+            for (token, light) in self.parse_list(')')? {
+                if light >= lights as u64 {
+                    return Err(self
+                        .source
+                        .unexpected(token, format_args!("a light below {lights}")));
+                }
+                button |= 1 << light;
+            }
+            buttons.push(button);
+
+            self.eat_whitespace();
+        }
+
+        Ok(buttons)
+    }
+
+    /// One joltage for each of the `lights` counters.
+    fn parse_joltages(&mut self, lights: usize) -> Result<Vec<u64>, ParseError> {
+        let start = self.offset();
+        self.expect('{')?;
+        let joltages: Vec<u64> = self
+            .parse_list('}')?
+            .into_iter()
+            .map(|(_token, joltage)| joltage)
+            .collect();
+
+        if joltages.len() != lights {
+            let end = self.offset();
+            return Err(self
+                .source
+                .unexpected(&self.line[start..end], format_args!("{lights} joltages")));
+        }
+
+        Ok(joltages)
+    }
+
+    /// Comma separated numbers up to and including `close`, each with its token.
+    fn parse_list(&mut self, close: char) -> Result<Vec<(&'src str, u64)>, ParseError> {
+        let mut values = Vec::new();
+
+        loop {
+            let start = self.offset();
+            while let Some((_, '0'..='9')) = self.chars.peek() {
+                self.chars.next();
+            }
+            let end = self.offset();
+            let token = &self.line[start..end];
+            values.push((token, self.source.number(token)?));
+
+            match self.chars.next() {
+                Some((_, ',')) => {}
+                Some((_, ch)) if ch == close => return Ok(values),
+                next => return Err(self.unexpected(next, format_args!("`,` or `{close}`"))),
+            }
+        }
+    }
+
+    fn expect(&mut self, ch: char) -> Result<(), ParseError> {
+        match self.chars.next() {
+            Some((_, next)) if next == ch => Ok(()),
+            next => Err(self.unexpected(next, format_args!("`{ch}`"))),
+        }
+    }
+
+    fn eat_whitespace(&mut self) {
+        while let Some((_, ch)) = self.chars.peek() {
+            if !ch.is_whitespace() {
+                break;
+            }
+
+            self.chars.next();
+        }
+    }
+
+    fn offset(&mut self) -> usize {
+        self.chars.peek().map_or(self.line.len(), |&(i, _)| i)
+    }
+
+    fn unexpected(&self, next: Option<(usize, char)>, expected: impl Display) -> ParseError {
+        let token = match next {
+            Some((i, ch)) => &self.line[i..i + ch.len_utf8()],
+            None => &self.line[self.line.len()..],
+        };
+
+        self.source.unexpected(token, expected)
+    }
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    lights: usize,
+    buttons: Vec<usize>,
+    joltages: Vec<u64>,
+}
+
+impl Machine {
+    fn min_joltage_presses(&self) -> Result<u64, JoltageError> {
+        let system = JoltageSystem::new(self)?;
+        let mut best = None;
+        system.search(&mut Vec::new(), 0, &mut best)?;
+
+        best.map(|best| best as u64)
+            .ok_or(JoltageError::Unreachable)
+    }
+}
+
+/// Why a machine's joltages can't be reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoltageError {
+    /// No number of presses of each button adds up to the joltages.
+    Unreachable,
+    Overflow,
+}
+
+impl Display for JoltageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoltageError::Unreachable => write!(f, "no presses of the buttons reach the joltages"),
+            JoltageError::Overflow => write!(f, "solving for the presses overflows an `i64`"),
+        }
+    }
+}
+
+impl std::error::Error for JoltageError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MachineError {
+    pub machine: usize,
+    pub error: JoltageError,
+}
+
+impl Display for MachineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "machine {}: {}", self.machine, self.error)
+    }
+}
+
+impl std::error::Error for MachineError {}
+
+/// The fewest presses for every machine's joltages, or the first machine that stopped them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Presses(pub Result<u64, MachineError>);
+
+impl Display for Presses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Ok(presses) => write!(f, "{presses}"),
+            Err(error) => write!(f, "error: {error}"),
+        }
+    }
+}
+
+/// `buttons * presses = joltages` reduced by Gaussian elimination. Each pivot button is
+/// determined by the free buttons, so only the free buttons (usually very few) need to be
+/// searched for the non-negative integer solution with the fewest presses.
+#[derive(Debug)]
+struct JoltageSystem {
+    /// The augmented matrix, with one row per counter and one column per button.
+    matrix: Vec<Vec<i64>>,
+    pivots: Vec<usize>,
+    free: Vec<usize>,
+    bounds: Vec<i64>,
+    /// The pivot rows that can be solved once the first `n` free buttons have been chosen.
+    checks: Vec<Vec<usize>>,
+}
+
+impl JoltageSystem {
+    /// Reduces the machine's system, or finds that it has no solution at all because
+    /// elimination leaves a counter that no button changes but still needs a joltage.
+    fn new(machine: &Machine) -> Result<Self, JoltageError> {
+        let counters = machine.joltages.len();
+        let button_count = machine.buttons.len();
+        let joltages: Vec<i64> = machine
+            .joltages
+            .iter()
+            .map(|&joltage| i64::try_from(joltage).map_err(|_| JoltageError::Overflow))
+            .collect::<Result<_, _>>()?;
+
+        let mut matrix: Vec<Vec<i64>> = (0..counters)
+            .map(|counter| {
+                let mut row: Vec<i64> = machine
+                    .buttons
+                    .iter()
+                    .map(|button| ((button >> counter) & 1) as i64)
+                    .collect();
+                row.push(joltages[counter]);
+                row
+            })
+            .collect();
+
+        let mut pivots = Vec::new();
+
+        for col in 0..button_count {
+            let row = pivots.len();
+            if row == counters {
+                break;
+            }
+
+            let Some(pivot_row) = (row..counters).find(|&r| matrix[r][col] != 0) else {
+                continue;
+            };
+            matrix.swap(row, pivot_row);
+
+            // Fraction-free elimination keeps everything in integers.
+            let pivot = matrix[row].clone();
+            for (other_index, other) in matrix.iter_mut().enumerate() {
+                let factor = other[col];
+                if other_index == row || factor == 0 {
+                    continue;
+                }
+
+                for (value, pivot_value) in other.iter_mut().zip(&pivot) {
+                    *value = value
+                        .checked_mul(pivot[col])
+                        .zip(pivot_value.checked_mul(factor))
+                        .and_then(|(value, pivot_value)| value.checked_sub(pivot_value))
+                        .ok_or(JoltageError::Overflow)?;
+                }
+                reduce(other);
+            }
+
+            pivots.push(col);
+        }
+
+        // The rows past the pivots have no buttons left in them, so they only hold if their
+        // joltage has been eliminated too.
+        if matrix[pivots.len()..]
+            .iter()
+            .any(|row| row[button_count] != 0)
+        {
+            return Err(JoltageError::Unreachable);
+        }
+
+        let free: Vec<usize> = (0..button_count)
+            .filter(|button| !pivots.contains(button))
+            .collect();
+
+        // A button can't be pressed more times than the smallest counter it increments.
+        let bounds = free
+            .iter()
+            .map(|&button| {
+                (0..counters)
+                    .filter(|counter| (machine.buttons[button] >> counter) & 1 == 1)
+                    .map(|counter| joltages[counter])
+                    .min()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let mut checks = vec![Vec::new(); free.len() + 1];
+        for (row, coefficients) in matrix.iter().enumerate().take(pivots.len()) {
+            let needed = free
+                .iter()
+                .rposition(|&button| coefficients[button] != 0)
+                .map_or(0, |position| position + 1);
+            checks[needed].push(row);
+        }
+
+        Ok(Self {
+            matrix,
+            pivots,
+            free,
+            bounds,
+            checks,
+        })
+    }
+
+    fn search(
+        &self,
+        chosen: &mut Vec<i64>,
+        total: i64,
+        best: &mut Option<i64>,
+    ) -> Result<(), JoltageError> {
+        let mut total = total;
+
+        for &row in &self.checks[chosen.len()] {
+            let Some(presses) = self.pivot_presses(row, chosen)? else {
+                return Ok(());
+            };
+            total = total.checked_add(presses).ok_or(JoltageError::Overflow)?;
+        }
+
+        if best.is_some_and(|best| total >= best) {
+            return Ok(());
+        }
+
+        if chosen.len() == self.free.len() {
+            *best = Some(total);
+            return Ok(());
+        }
+
+        for presses in 0..=self.bounds[chosen.len()] {
+            let total = total.checked_add(presses).ok_or(JoltageError::Overflow)?;
+            if best.is_some_and(|best| total >= best) {
+                break;
+            }
+
+            chosen.push(presses);
+            self.search(chosen, total, best)?;
+            chosen.pop();
+        }
+
+        Ok(())
+    }
+
+    /// How many times the row's pivot button is pressed given the chosen free buttons, or
+    /// `None` if that isn't a whole, non-negative number.
+    fn pivot_presses(&self, row: usize, chosen: &[i64]) -> Result<Option<i64>, JoltageError> {
+        let pivot = self.matrix[row][self.pivots[row]];
+        let remaining = self
+            .free
+            .iter()
+            .zip(chosen)
+            .try_fold(
+                self.matrix[row][self.matrix[row].len() - 1],
+                |remaining, (&button, presses)| {
+                    remaining.checked_sub(self.matrix[row][button].checked_mul(*presses)?)
+                },
+            )
+            .ok_or(JoltageError::Overflow)?;
+
+        if remaining % pivot != 0 || remaining / pivot < 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(remaining / pivot))
+    }
+}
+
+fn reduce(row: &mut [i64]) {
+    let divisor = row
+        .iter()
+        .fold(0, |divisor, &value| gcd(divisor, value.abs()));
+
+    if divisor > 1 {
+        for value in row.iter_mut() {
+            *value /= divisor;
+        }
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+impl Solution for Day10 {
+    type Input<'src> = Vec<Machine>;
+    type PartOne = usize;
+    type PartTwo = Presses;
+
+    fn parse<'src>(&self, input: &'src str) -> Result<Self::Input<'src>, ParseError> {
+        let source = Source::new(input);
+        input
+            .lines()
+            .map(|line| MachineParser::new(source, line).parse())
+            .collect()
+    }
+
+    fn part_one(&self, machines: &Self::Input<'_>) -> usize {
+        machines
+            .iter()
+            .map(|machine| {
+                (1..=machine.buttons.len())
+                    .find(|&presses| {
+                        machine.buttons.iter().combinations(presses).any(|buttons| {
+                            buttons
+                                .into_iter()
+                                .fold(0usize, |lights, button| lights ^ button)
+                                == machine.lights
+                        })
+                    })
+                    .unwrap()
+            })
+            .sum()
+    }
+
+    fn part_two(&self, machines: &Self::Input<'_>) -> Presses {
+        Presses(
+            machines
+                .iter()
+                .enumerate()
+                .try_fold(0u64, |total, (i, machine)| {
+                    machine
+                        .min_joltage_presses()
+                        .and_then(|presses| {
+                            total.checked_add(presses).ok_or(JoltageError::Overflow)
+                        })
+                        .map_err(|error| MachineError {
+                            machine: i + 1,
+                            error,
+                        })
+                }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_ten_part_one() {
+        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+        assert_eq!(Day10.part_one(&Day10.parse(input).unwrap()), 7);
+    }
+
+    #[test]
+    fn day_ten_part_two() {
+        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+        assert_eq!(
+            Day10.part_two(&Day10.parse(input).unwrap()),
+            Presses(Ok(33))
+        );
+    }
+
+    #[test]
+    fn day_ten_inconsistent_joltages() {
+        // Both buttons change both counters together, so they can't end up different.
+        let machines = Day10
+            .parse("[##] (0,1) (0,1) {3,5}\n[##] (0,1) (0,1) {4,4}")
+            .unwrap();
+
+        assert_eq!(
+            machines[0].min_joltage_presses(),
+            Err(JoltageError::Unreachable)
+        );
+        assert_eq!(machines[1].min_joltage_presses(), Ok(4));
+        assert_eq!(
+            Day10.part_two(&machines).to_string(),
+            "error: machine 1: no presses of the buttons reach the joltages"
+        );
+    }
+
+    #[test]
+    fn day_ten_free_buttons() {
+        // Three buttons for two counters leaves one free, and the search has to find that
+        // pressing the button that does both is cheapest.
+        let machines = Day10
+            .parse("[##] (0) (1) (0,1) {5,7}\n[###] (0,1) (1,2) (0,2) (0) {3,2,1}")
+            .unwrap();
+
+        let system = JoltageSystem::new(&machines[0]).unwrap();
+        assert_eq!(system.free.len(), 1);
+        assert_eq!(machines[0].min_joltage_presses(), Ok(7));
+
+        // Four buttons for three counters, where the cheapest way leaves the last one alone.
+        let system = JoltageSystem::new(&machines[1]).unwrap();
+        assert_eq!(system.free.len(), 1);
+        assert_eq!(machines[1].min_joltage_presses(), Ok(3));
+    }
+
+    #[test]
+    fn day_ten_parse_error() {
+        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2;3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}";
+
+        let error = Day10.parse(input).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 21: expected `,` or `)`, found `;`"
+        );
+    }
+
+    #[test]
+    fn day_ten_button_errors() {
+        let error = Day10.parse("[.#] (0) (70) {1,2}").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 11: expected a light below 2, found `70`"
+        );
+
+        let error = Day10.parse("[.#] (0) (1) {1}").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 14: expected 2 joltages, found `{1}`"
+        );
+
+        let input = format!("[{}] (0) {{1}}", ".".repeat(65));
+        let error = Day10.parse(&input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 66: expected `]` after at most 64 lights, found `.`"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use common::{ParseError, Solution, Source};
+
+#[derive(Debug, Default)]
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'src> = HashMap<&'src str, Vec<&'src str>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse<'src>(&self, input: &'src str) -> Result<Self::Input<'src>, ParseError> {
+        let source = Source::new(input);
+        input
+            .lines()
+            .map(|line| {
+                let (device, outputs) = source.split_once(line, ':')?;
+                Ok((device, outputs.split_whitespace().collect()))
+            })
+            .collect()
+    }
+
+    fn part_one(&self, map: &Self::Input<'_>) -> usize {
+        fn traverse(device: &str, map: &HashMap<&str, Vec<&str>>) -> usize {
+            map.get(device)
+                .unwrap()
+                .iter()
+                .map(|&output| match output {
+                    "out" => 1,
+                    _ => traverse(output, map),
+                })
+                .sum()
+        }
+
+        traverse("you", map)
+    }
+
+    fn part_two(&self, map: &Self::Input<'_>) -> usize {
+        let mut cache = HashMap::new();
+
+        fn traverse<'src>(
+            device: &'src str,
+            visited_fft: bool,
+            visited_dac: bool,
+            map: &HashMap<&'src str, Vec<&'src str>>,
+            cache: &mut HashMap<(&'src str, bool, bool), usize>,
+        ) -> usize {
+            if let Some(result) = cache.get(&(device, visited_fft, visited_dac)) {
+                return *result;
+            }
+
+            let result = map
+                .get(device)
+                .unwrap()
+                .iter()
+                .map(|&output| match output {
+                    "out" => {
+                        if visited_fft && visited_dac {
+                            1
+                        } else {
+                            0
+                        }
+                    }
+                    "fft" => traverse(output, true, visited_dac, map, cache),
+                    "dac" => traverse(output, visited_fft, true, map, cache),
+                    _ => traverse(output, visited_fft, visited_dac, map, cache),
+                })
+                .sum();
+
+            cache.insert((device, visited_fft, visited_dac), result);
+            result
+        }
+
+        traverse("svr", false, false, map, &mut cache)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_eleven_part_one() {
+        let input = "aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out";
+        assert_eq!(Day11.part_one(&Day11.parse(input).unwrap()), 5);
+    }
+
+    #[test]
+    fn day_eleven_part_two() {
+        let input = "svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out";
+        assert_eq!(Day11.part_two(&Day11.parse(input).unwrap()), 2);
+    }
+}
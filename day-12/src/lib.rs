@@ -0,0 +1,479 @@
+use std::{cmp, collections::HashSet};
+
+use common::{NoAnswer, ParseError, Solution, Source};
+
+#[derive(Debug, Default)]
+pub struct Day12;
+
+#[derive(Debug)]
+struct Shape {
+    units: Vec<Vec<Unit>>,
+}
+
+impl Shape {
+    /// Parses the shape under its `index:` header. A shape needs at least one `#`.
+    fn parse(source: &Source, shape: &str, index: usize) -> Result<Self, ParseError> {
+        let mut lines = shape.lines();
+        let header = lines.next().unwrap_or(shape);
+        let (id, rest) = source.split_once(header, ':')?;
+
+        if source.number::<usize>(id)? != index {
+            return Err(source.unexpected(id, format_args!("shape {index}")));
+        }
+        if !rest.is_empty() {
+            return Err(source.unexpected(rest, "end of line"));
+        }
+
+        let units: Vec<Vec<Unit>> = lines
+            .map(|line| {
+                line.char_indices()
+                    .map(|(i, ch)| match ch {
+                        '#' => Ok(Unit::Occupied),
+                        '.' => Ok(Unit::Empty),
+                        _ => Err(source.unexpected(&line[i..i + ch.len_utf8()], "`#` or `.`")),
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+
+        if !units
+            .iter()
+            .flatten()
+            .any(|unit| matches!(unit, Unit::Occupied))
+        {
+            return Err(source.missing_after(shape.trim_end(), "a `#`"));
+        }
+
+        Ok(Self { units })
+    }
+
+    fn area(&self) -> usize {
+        self.units
+            .iter()
+            .flat_map(|row| {
+                row.iter().map(|unit| match unit {
+                    Unit::Occupied => 1,
+                    Unit::Empty => 0,
+                })
+            })
+            .sum()
+    }
+
+    fn width(&self) -> usize {
+        self.units.iter().map(|row| row.len()).max().unwrap_or(0)
+    }
+
+    fn height(&self) -> usize {
+        self.units.len()
+    }
+
+    /// Every distinct rotation and reflection of the shape, each given as `(row, col)` offsets
+    /// from its first occupied unit in reading order.
+    fn orientations(&self) -> Vec<Vec<(isize, isize)>> {
+        let mut units: Vec<(isize, isize)> = self
+            .units
+            .iter()
+            .enumerate()
+            .flat_map(|(row_index, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_col_index, unit)| matches!(unit, Unit::Occupied))
+                    .map(move |(col_index, _unit)| (row_index as isize, col_index as isize))
+            })
+            .collect();
+
+        let mut orientations = Vec::new();
+
+        for _ in 0..2 {
+            for _ in 0..4 {
+                units = units.iter().map(|&(row, col)| (col, -row)).collect();
+
+                let orientation = normalise(&units);
+                if !orientations.contains(&orientation) {
+                    orientations.push(orientation);
+                }
+            }
+
+            units = units.iter().map(|&(row, col)| (row, -col)).collect();
+        }
+
+        orientations
+    }
+}
+
+/// Shifts the units so the first in reading order is at `(0, 0)`. There must be at least one.
+fn normalise(units: &[(isize, isize)]) -> Vec<(isize, isize)> {
+    let mut units = units.to_vec();
+    units.sort_unstable();
+
+    let (first_row, first_col) = units[0];
+    units
+        .iter()
+        .map(|&(row, col)| (row - first_row, col - first_col))
+        .collect()
+}
+
+#[derive(Debug)]
+enum Unit {
+    Occupied,
+    Empty,
+}
+
+#[derive(Debug)]
+struct Region {
+    width: usize,
+    height: usize,
+    counts: Vec<usize>,
+}
+
+impl Region {
+    fn parse(source: &Source, region: &str, shapes: usize) -> Result<Self, ParseError> {
+        let (dimensions, counts) = source.split_once(region, ':')?;
+        let counts = counts
+            .split_whitespace()
+            .enumerate()
+            .map(|(shape_index, count)| {
+                if shape_index >= shapes {
+                    return Err(source.unexpected(count, format_args!("only {shapes} counts")));
+                }
+
+                source.number(count)
+            })
+            .collect::<Result<_, _>>()?;
+
+        let (width, height) = source.split_once(dimensions, 'x')?;
+
+        Ok(Self {
+            width: source.number(width)?,
+            height: source.number(height)?,
+            counts,
+        })
+    }
+
+    fn fits(&self, shapes: &[Shape]) -> bool {
+        let area = self.width * self.height;
+        let required_area: usize = self
+            .counts
+            .iter()
+            .enumerate()
+            .map(|(shape_index, count)| shapes[shape_index].area() * count)
+            .sum();
+
+        if required_area > area {
+            return false;
+        }
+
+        // if every present gets its own bounding box there's no packing to do, and every shape
+        // has a `#` so the boxes are never empty
+        let block_width = shapes.iter().map(Shape::width).max().unwrap_or(1);
+        let block_height = shapes.iter().map(Shape::height).max().unwrap_or(1);
+        let blocks = (self.width / block_width) * (self.height / block_height);
+
+        if self.counts.iter().sum::<usize>() <= blocks {
+            return true;
+        }
+
+        self.packing(shapes)
+            .solve(0, area - required_area, required_area)
+    }
+
+    fn packing(&self, shapes: &[Shape]) -> Packing {
+        // shapes can be rotated, so scan along the shorter side to keep the window small
+        let width = cmp::min(self.width, self.height);
+        let height = cmp::max(self.width, self.height);
+        let extent = shapes
+            .iter()
+            .map(|shape| cmp::max(shape.width(), shape.height()))
+            .max()
+            .unwrap_or(1);
+
+        Packing {
+            width,
+            height,
+            occupied: vec![false; width * height],
+            orientations: shapes.iter().map(Shape::orientations).collect(),
+            areas: shapes.iter().map(Shape::area).collect(),
+            remaining: self.counts.clone(),
+            reach: (extent - 1) * width + extent,
+            dead_ends: HashSet::new(),
+        }
+    }
+}
+
+/// Backtracking search that visits the cells of the region in reading order. The first empty
+/// cell is either covered by the first unit of some remaining shape or left empty for good,
+/// which uses up some of the slack the region has over the total area of the shapes.
+///
+/// Shapes never reach more than `reach` cells past the cell they're placed on, so the cells in
+/// that window together with the remaining shapes decide whether the rest of the region can be
+/// packed. Windows that have already failed are remembered in `dead_ends`.
+#[derive(Debug)]
+struct Packing {
+    width: usize,
+    height: usize,
+    occupied: Vec<bool>,
+    orientations: Vec<Vec<Vec<(isize, isize)>>>,
+    areas: Vec<usize>,
+    remaining: Vec<usize>,
+    reach: usize,
+    dead_ends: HashSet<(usize, Vec<bool>, Vec<usize>)>,
+}
+
+impl Packing {
+    fn solve(&mut self, from: usize, slack: usize, required_area: usize) -> bool {
+        if required_area == 0 {
+            return true;
+        }
+
+        let Some(cell) = (from..self.occupied.len()).find(|&cell| !self.occupied[cell]) else {
+            return false;
+        };
+
+        let window = self.occupied[cell..self.occupied.len().min(cell + self.reach)].to_vec();
+        let state = (cell, window, self.remaining.clone());
+        if self.dead_ends.contains(&state) {
+            return false;
+        }
+
+        let solved = self.solve_from(cell, slack, required_area);
+        if !solved {
+            self.dead_ends.insert(state);
+        }
+
+        solved
+    }
+
+    fn solve_from(&mut self, cell: usize, slack: usize, required_area: usize) -> bool {
+        for shape_index in 0..self.remaining.len() {
+            if self.remaining[shape_index] == 0 {
+                continue;
+            }
+
+            for orientation_index in 0..self.orientations[shape_index].len() {
+                let Some(units) =
+                    self.place(cell, &self.orientations[shape_index][orientation_index])
+                else {
+                    continue;
+                };
+
+                for &unit in &units {
+                    self.occupied[unit] = true;
+                }
+                self.remaining[shape_index] -= 1;
+
+                let solved = self.solve(cell + 1, slack, required_area - self.areas[shape_index]);
+
+                self.remaining[shape_index] += 1;
+                for &unit in &units {
+                    self.occupied[unit] = false;
+                }
+
+                if solved {
+                    return true;
+                }
+            }
+        }
+
+        if slack == 0 {
+            return false;
+        }
+
+        self.occupied[cell] = true;
+        let solved = self.solve(cell + 1, slack - 1, required_area);
+        self.occupied[cell] = false;
+
+        solved
+    }
+
+    /// The cells covered by the orientation when its first unit sits on `cell`, if they are all
+    /// inside the region and empty.
+    fn place(&self, cell: usize, orientation: &[(isize, isize)]) -> Option<Vec<usize>> {
+        let row = (cell / self.width) as isize;
+        let col = (cell % self.width) as isize;
+
+        orientation
+            .iter()
+            .map(|&(row_offset, col_offset)| {
+                let new_row = row + row_offset;
+                let new_col = col + col_offset;
+
+                if new_row < 0
+                    || new_col < 0
+                    || new_row >= self.height as isize
+                    || new_col >= self.width as isize
+                {
+                    return None;
+                }
+
+                let unit = new_row as usize * self.width + new_col as usize;
+                (!self.occupied[unit]).then_some(unit)
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct Farm {
+    shapes: Vec<Shape>,
+    regions: Vec<Region>,
+}
+
+impl Solution for Day12 {
+    type Input<'src> = Farm;
+    type PartOne = usize;
+    type PartTwo = NoAnswer;
+
+    fn parse<'src>(&self, input: &'src str) -> Result<Self::Input<'src>, ParseError> {
+        let source = Source::new(input);
+        let sections = input.split("\n\n").collect::<Vec<_>>();
+        let (regions, shapes) = sections.split_last().unwrap();
+
+        let shapes: Vec<Shape> = shapes
+            .iter()
+            .enumerate()
+            .map(|(index, shape)| Shape::parse(&source, shape, index))
+            .collect::<Result<_, _>>()?;
+
+        let regions: Vec<Region> = regions
+            .lines()
+            .map(|region| Region::parse(&source, region, shapes.len()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Farm { shapes, regions })
+    }
+
+    fn part_one(&self, farm: &Self::Input<'_>) -> usize {
+        farm.regions
+            .iter()
+            .filter(|region| region.fits(&farm.shapes))
+            .count()
+    }
+
+    fn part_two(&self, _farm: &Self::Input<'_>) -> NoAnswer {
+        NoAnswer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_twelve_part_one() {
+        let input = "0:
+###
+##.
+##.
+
+1:
+###
+##.
+.##
+
+2:
+.##
+###
+##.
+
+3:
+##.
+###
+##.
+
+4:
+###
+#..
+###
+
+5:
+###
+.#.
+###
+
+4x4: 0 0 0 0 2 0
+12x5: 1 0 1 0 2 2
+12x5: 1 0 1 0 3 2";
+        assert_eq!(Day12.part_one(&Day12.parse(input).unwrap()), 2);
+    }
+
+    fn shape(shape: &str) -> Shape {
+        Shape::parse(&Source::new(shape), shape, 0).unwrap()
+    }
+
+    #[test]
+    fn day_twelve_orientations() {
+        assert_eq!(
+            shape("0:\n##\n##").orientations(),
+            [[(0, 0), (0, 1), (1, 0), (1, 1)]]
+        );
+
+        let line = shape("0:\n###").orientations();
+        assert_eq!(line.len(), 2);
+        assert!(line.contains(&vec![(0, 0), (0, 1), (0, 2)]));
+        assert!(line.contains(&vec![(0, 0), (1, 0), (2, 0)]));
+
+        // the L tromino's reflections are its rotations, the L tetromino's aren't
+        assert_eq!(shape("0:\n##\n#.").orientations().len(), 4);
+        assert_eq!(shape("0:\n###\n#..").orientations().len(), 8);
+        assert_eq!(shape("0:\n###\n.#.").orientations().len(), 4);
+    }
+
+    #[test]
+    fn day_twelve_packing() {
+        let tromino = [shape("0:\n##\n#.")];
+        let region = |width, height, count| Region {
+            width,
+            height,
+            counts: vec![count],
+        };
+
+        // two trominoes make a 3x2 rectangle, but three can't fill a 3x3 square
+        assert!(region(3, 2, 2).fits(&tromino));
+        assert!(region(2, 3, 2).fits(&tromino));
+        assert!(!region(3, 3, 3).fits(&tromino));
+        assert!(region(4, 3, 3).fits(&tromino));
+    }
+
+    #[test]
+    fn day_twelve_dead_ends() {
+        let tromino = [shape("0:\n##\n#.")];
+        let region = Region {
+            width: 3,
+            height: 3,
+            counts: vec![3],
+        };
+
+        let mut packing = region.packing(&tromino);
+        assert!(!packing.solve(0, 0, 9));
+
+        let start = (0, vec![false; packing.reach], vec![3]);
+        assert!(packing.dead_ends.contains(&start));
+
+        // the second search stops at the start, without finding any new dead ends
+        let dead_ends = packing.dead_ends.len();
+        assert!(!packing.solve(0, 0, 9));
+        assert_eq!(packing.dead_ends.len(), dead_ends);
+    }
+
+    #[test]
+    fn day_twelve_parse_errors() {
+        let error = |input| Day12.parse(input).unwrap_err().to_string();
+
+        assert_eq!(
+            error("0:\n...\n\n3x3: 1"),
+            "line 2, column 4: expected a `#`, found end of line"
+        );
+        assert_eq!(
+            error("0:\n#\n\n2:\n#\n\n3x3: 1 1"),
+            "line 4, column 1: expected shape 1, found `2`"
+        );
+        assert_eq!(
+            error("0: #\n\n3x3: 1"),
+            "line 1, column 3: expected end of line, found ` #`"
+        );
+        assert_eq!(
+            error("#\n\n3x3: 1"),
+            "line 1, column 2: expected `:`, found end of line"
+        );
+    }
+}
@@ -0,0 +1,331 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use common::{ParseError, Source};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// The position `(row, col)` away, if it doesn't go below zero.
+    pub fn offset(self, (row_offset, col_offset): (isize, isize)) -> Option<Self> {
+        Some(Self {
+            row: self.row.checked_add_signed(row_offset)?,
+            col: self.col.checked_add_signed(col_offset)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// The direction a quarter turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        self.turn_right().turn_right().turn_right()
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+}
+
+/// Offsets to the four orthogonal neighbours, clockwise from straight up.
+pub const ORTHOGONAL_OFFSETS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets to the eight surrounding cells, clockwise from straight up.
+pub const ALL_OFFSETS: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, cell: T) -> Self {
+        Self {
+            cells: vec![cell; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map, turning each character into a cell with `cell`, which is also
+    /// given the character's slice of the input for error reporting. Every line must be as wide
+    /// as the first.
+    pub fn parse<'src>(
+        source: &Source,
+        text: &'src str,
+        mut cell: impl FnMut(char, &'src str) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in text.lines() {
+            let start = cells.len();
+
+            for (i, ch) in line.char_indices() {
+                cells.push(cell(ch, &line[i..i + ch.len_utf8()])?);
+            }
+
+            let line_width = cells.len() - start;
+            let width = *width.get_or_insert(line_width);
+
+            if line_width < width {
+                return Err(source.missing_after(line, format_args!("{width} cells")));
+            }
+            if line_width > width {
+                let (extra, _) = line.char_indices().nth(width).unwrap();
+                return Err(source.unexpected(&line[extra..], "end of line"));
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.row < self.height && position.col < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.index(position)])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if !self.contains(position) {
+            return None;
+        }
+
+        let index = self.index(position);
+        Some(&mut self.cells[index])
+    }
+
+    /// The position `offset` away, if it's inside the grid.
+    pub fn offset(&self, position: Position, offset: (isize, isize)) -> Option<Position> {
+        position
+            .offset(offset)
+            .filter(|&position| self.contains(position))
+    }
+
+    /// The position `offset` away, wrapping around the edges as if the grid were a torus.
+    pub fn wrapping_offset(
+        &self,
+        position: Position,
+        (row_offset, col_offset): (isize, isize),
+    ) -> Position {
+        let wrap = |value: usize, offset: isize, len: usize| {
+            (value as isize + offset).rem_euclid(len as isize) as usize
+        };
+
+        Position {
+            row: wrap(position.row, row_offset, self.height),
+            col: wrap(position.col, col_offset, self.width),
+        }
+    }
+
+    /// The neighbouring position in `direction`, if it's inside the grid.
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        self.offset(position, direction.offset())
+    }
+
+    /// The up to four orthogonal neighbours of `position`.
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The up to eight neighbours of `position`, diagonals included.
+    pub fn all_neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ALL_OFFSETS
+            .into_iter()
+            .filter_map(move |offset| self.offset(position, offset))
+    }
+
+    /// Every position in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Position { row, col }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The first position, in reading order, whose cell matches.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_position, cell)| predicate(cell))
+            .map(|(position, _cell)| position)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn index(&self, position: Position) -> usize {
+        position.row * self.width + position.col
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        assert!(self.contains(position), "{position:?} is outside the grid");
+        &self.cells[self.index(position)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        assert!(self.contains(position), "{position:?} is outside the grid");
+        let index = self.index(position);
+        &mut self.cells[index]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_display() {
+        let input = "#.#\n.#.\n";
+        let source = Source::new(input);
+
+        let grid = Grid::parse(&source, input, |ch, _token| Ok(ch)).unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Position::new(1, 1)], '#');
+        assert_eq!(grid.to_string(), input);
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let input = "#.#\n.#\n#.#\n";
+        let source = Source::new(input);
+
+        let error = Grid::parse(&source, input, |ch, _token| Ok(ch)).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected 3 cells, found end of line"
+        );
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = Grid::new(3, 3, ());
+
+        assert_eq!(grid.neighbours(Position::new(0, 0)).count(), 2);
+        assert_eq!(grid.all_neighbours(Position::new(0, 0)).count(), 3);
+        assert_eq!(grid.all_neighbours(Position::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn wrapping_offset() {
+        let grid = Grid::new(4, 3, ());
+
+        assert_eq!(
+            grid.wrapping_offset(Position::new(0, 0), (-1, -1)),
+            Position::new(2, 3)
+        );
+        assert_eq!(
+            grid.wrapping_offset(Position::new(2, 3), (1, 2)),
+            Position::new(0, 1)
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use std::{
+    env, io, process,
+    time::{Duration, Instant},
+};
+
+use common::{InputOptions, ParseError, Puzzle, Solution};
+
+const USAGE: &str =
+    "usage: aoc run <day|all> [--part <1|2>] [--input <path|->] [--inputs-dir <dir>]";
+
+struct Day {
+    number: u8,
+    solve: fn(&str, Option<usize>) -> Result<Vec<Step>, ParseError>,
+}
+
+const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        solve: solve::<day_01::Day01>,
+    },
+    Day {
+        number: 2,
+        solve: solve::<day_02::Day02>,
+    },
+    Day {
+        number: 3,
+        solve: solve::<day_03::Day03>,
+    },
+    Day {
+        number: 4,
+        solve: solve::<day_04::Day04>,
+    },
+    Day {
+        number: 5,
+        solve: solve::<day_05::Day05>,
+    },
+    Day {
+        number: 6,
+        solve: solve::<day_06::Day06>,
+    },
+    Day {
+        number: 7,
+        solve: solve::<day_07::Day07>,
+    },
+    Day {
+        number: 8,
+        solve: solve::<day_08::Day08>,
+    },
+    Day {
+        number: 9,
+        solve: solve::<day_09::Day09>,
+    },
+    Day {
+        number: 10,
+        solve: solve::<day_10::Day10>,
+    },
+    Day {
+        number: 11,
+        solve: solve::<day_11::Day11>,
+    },
+    Day {
+        number: 12,
+        solve: solve::<day_12::Day12>,
+    },
+];
+
+#[derive(Debug)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+#[derive(Debug)]
+struct Args {
+    selection: Selection,
+    part: Option<usize>,
+    input: InputOptions,
+}
+
+impl Args {
+    fn parse(mut args: Vec<String>) -> Result<Self, String> {
+        let input = InputOptions::extract(&mut args)?;
+        let mut args = args.into_iter();
+
+        match args.next().as_deref() {
+            Some("run") => {}
+            Some(command) => return Err(format!("unknown command `{command}`")),
+            None => return Err(String::from("missing command")),
+        }
+
+        let selection = match args.next().as_deref() {
+            Some("all") => Selection::All,
+            Some(day) => Selection::Day(
+                day.parse()
+                    .map_err(|_| format!("`{day}` is not a day number"))?,
+            ),
+            None => return Err(String::from("missing day")),
+        };
+
+        let mut part = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" | "-p" => {
+                    let value = args.next().ok_or("`--part` needs a value")?;
+                    part = match value.as_str() {
+                        "1" => Some(1),
+                        "2" => Some(2),
+                        _ => return Err(format!("`{value}` is not a part number")),
+                    };
+                }
+                _ => return Err(format!("unexpected argument `{arg}`")),
+            }
+        }
+
+        if input.path.is_some() && matches!(selection, Selection::All) {
+            return Err(String::from(
+                "`--input` can only be used when running a single day",
+            ));
+        }
+
+        Ok(Self {
+            selection,
+            part,
+            input,
+        })
+    }
+}
+
+/// A timed step of solving a day: parsing the input or one of the parts.
+#[derive(Debug)]
+struct Step {
+    name: &'static str,
+    answer: String,
+    elapsed: Duration,
+}
+
+#[derive(Debug)]
+struct Row {
+    day: u8,
+    step: Step,
+}
+
+fn main() {
+    let args = match Args::parse(env::args().skip(1).collect()) {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("error: {error}\n{USAGE}");
+            process::exit(2);
+        }
+    };
+
+    let days: Vec<&Day> = match args.selection {
+        Selection::All => DAYS.iter().collect(),
+        Selection::Day(number) => match DAYS.iter().find(|day| day.number == number) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("error: day {number} has not been solved");
+                process::exit(2);
+            }
+        },
+    };
+
+    let mut rows = Vec::new();
+
+    for day in days {
+        let puzzle = Puzzle::workspace_day(env!("CARGO_MANIFEST_DIR"), day.number);
+
+        let input = match args.input.load(&puzzle) {
+            Ok(input) => input,
+            // When running every day, one without an input doesn't stop the rest.
+            Err(error)
+                if matches!(args.selection, Selection::All)
+                    && error.error.kind() == io::ErrorKind::NotFound =>
+            {
+                eprintln!("skipping {}: {error}", puzzle.name);
+                continue;
+            }
+            Err(error) => {
+                eprintln!("error: {error}");
+                process::exit(1);
+            }
+        };
+
+        let steps = match (day.solve)(&input, args.part) {
+            Ok(steps) => steps,
+            Err(error) => {
+                eprintln!(
+                    "{}\nnote: while parsing the input for day {}",
+                    error.render(&input),
+                    day.number
+                );
+                process::exit(1);
+            }
+        };
+
+        rows.extend(steps.into_iter().map(|step| Row {
+            day: day.number,
+            step,
+        }));
+    }
+
+    print_table(&rows);
+}
+
+fn solve<S: Solution>(input: &str, part: Option<usize>) -> Result<Vec<Step>, ParseError> {
+    let solution = S::default();
+    let mut steps = Vec::new();
+
+    let start = Instant::now();
+    let input = solution.parse(input)?;
+    let elapsed = start.elapsed();
+
+    steps.push(Step {
+        name: "parse",
+        answer: String::new(),
+        elapsed,
+    });
+
+    if part.is_none_or(|part| part == 1) {
+        let start = Instant::now();
+        let answer = solution.part_one(&input).to_string();
+        let elapsed = start.elapsed();
+
+        steps.push(Step {
+            name: "1",
+            answer,
+            elapsed,
+        });
+    }
+
+    if part.is_none_or(|part| part == 2) {
+        let start = Instant::now();
+        let answer = solution.part_two(&input).to_string();
+        let elapsed = start.elapsed();
+
+        steps.push(Step {
+            name: "2",
+            answer,
+            elapsed,
+        });
+    }
+
+    Ok(steps)
+}
+
+fn print_table(rows: &[Row]) {
+    let answer_width = rows
+        .iter()
+        .map(|row| row.step.answer.len())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap();
+
+    println!("Day  Part   {:<answer_width$}  Time", "Answer");
+
+    for row in rows {
+        println!(
+            "{:>3}  {:>5}  {:<answer_width$}  {:?}",
+            row.day, row.step.name, row.step.answer, row.step.elapsed
+        );
+    }
+
+    let total: Duration = rows.iter().map(|row| row.step.elapsed).sum();
+    println!("{:>10}  {:<answer_width$}  {total:?}", "", "Total");
+}